@@ -4,12 +4,14 @@ import { Progress } from "@/components/ui/progress"
 import { Badge } from "@/components/ui/badge"
 import { Button } from "@/components/ui/button"
 import { cn } from "@/lib/utils"
+import { fromTokenUnits } from "@/lib/amounts"
 
 type Need = {
   id: string
   title: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
 }
@@ -46,8 +48,8 @@ export function NeedCard({ need }: NeedCardProps) {
         <div className="space-y-2">
           <Progress value={progress} />
           <div className="flex justify-between text-sm">
-            <span className="font-semibold">${(need.amountRaised / 100).toLocaleString()}</span>
-            <span className="text-muted-foreground">raised of ${(need.amountNeeded / 100).toLocaleString()}</span>
+            <span className="font-semibold">${fromTokenUnits(need.amountRaised, need.decimals).toLocaleString()}</span>
+            <span className="text-muted-foreground">raised of ${fromTokenUnits(need.amountNeeded, need.decimals).toLocaleString()}</span>
           </div>
         </div>
       </CardContent>
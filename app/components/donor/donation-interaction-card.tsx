@@ -13,6 +13,7 @@ import { PinProtectedAction } from "@/components/pin-protected-action"
 import { useWallet } from "@/contexts/WalletContext"
 import { useAuth } from "@/contexts/AuthContext"
 import { toast } from "sonner"
+import { fromTokenUnits } from "@/lib/amounts"
 
 type Need = {
   id: string
@@ -21,6 +22,7 @@ type Need = {
   place: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   walletAddress: string
   author: string
   authorAvatarUrl: string | null
@@ -213,8 +215,8 @@ export function DonationInteractionCard({ need, onDonationComplete }: DonationIn
             <Progress value={progress} />
           </div>
           <div className="flex justify-between text-base">
-            <span className="font-bold text-teal-600 dark:text-teal-400">${(need.amountRaised / 100).toLocaleString()}</span>
-            <span className="text-muted-foreground">raised of ${(need.amountNeeded / 100).toLocaleString()}</span>
+            <span className="font-bold text-teal-600 dark:text-teal-400">${fromTokenUnits(need.amountRaised, need.decimals).toLocaleString()}</span>
+            <span className="text-muted-foreground">raised of ${fromTokenUnits(need.amountNeeded, need.decimals).toLocaleString()}</span>
           </div>
         </div>
 
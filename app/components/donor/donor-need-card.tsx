@@ -3,6 +3,7 @@ import { Card, CardContent, CardDescription, CardFooter, CardHeader, CardTitle }
 import { Progress } from "@/components/ui/progress"
 import { Button } from "@/components/ui/button"
 import { Badge } from "@/components/ui/badge"
+import { fromTokenUnits } from "@/lib/amounts"
 
 type Need = {
   id: string
@@ -10,6 +11,7 @@ type Need = {
   place: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: string
   imageUrl: string
   type: string
@@ -42,8 +44,8 @@ export function DonorNeedCard({ need }: DonorNeedCardProps) {
         <div className="space-y-2">
           <Progress value={progress} />
           <div className="flex justify-between text-sm">
-            <span className="font-semibold">${(need.amountRaised / 100).toLocaleString()}</span>
-            <span className="text-muted-foreground">raised of ${(need.amountNeeded / 100).toLocaleString()}</span>
+            <span className="font-semibold">${fromTokenUnits(need.amountRaised, need.decimals).toLocaleString()}</span>
+            <span className="text-muted-foreground">raised of ${fromTokenUnits(need.amountNeeded, need.decimals).toLocaleString()}</span>
           </div>
         </div>
       </CardContent>
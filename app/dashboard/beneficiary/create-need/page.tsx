@@ -15,6 +15,7 @@ import { useWallet } from "@/contexts/WalletContext"
 import { useAuth } from "@/contexts/AuthContext"
 import { uploadReportImages, generateTempReportId } from "@/lib/firebase-storage"
 import { toast } from "sonner"
+import { toTokenUnits, DEFAULT_TOKEN_DECIMALS } from "@/lib/amounts"
 import { PinProtectedAction } from "@/components/pin-protected-action"
 
 interface TransactionResult {
@@ -41,6 +42,8 @@ export default function CreateNeedPage() {
   const [title, setTitle] = useState("")
   const [description, setDescription] = useState("")
   const [location, setLocation] = useState("")
+  const [countryCode, setCountryCode] = useState("CO")
+  const [region, setRegion] = useState("")
   const [category, setCategory] = useState("")
   const [amountNeeded, setAmountNeeded] = useState("")
 
@@ -100,12 +103,18 @@ export default function CreateNeedPage() {
   }
 
   const performFormSubmission = async (privateKey: string) => {
-    if (!title || !description || !location || !category || !amountNeeded) {
+    if (!title || !description || !location || !countryCode || !region || !category || !amountNeeded) {
       console.error('❌ Missing required fields')
       toast.error("Please fill in all required fields")
       return
     }
 
+    if (!/^[A-Za-z]{2}$/.test(countryCode)) {
+      console.error('❌ Invalid country code:', countryCode)
+      toast.error("Please enter a two-letter country code (e.g. CO)")
+      return
+    }
+
     const amount = parseFloat(amountNeeded)
     if (isNaN(amount) || amount <= 0) {
       console.error('❌ Invalid amount:', amountNeeded)
@@ -143,8 +152,12 @@ export default function CreateNeedPage() {
         title,
         description,
         location,
+        geo: {
+          countryCode: countryCode.toUpperCase(),
+          region
+        },
         category,
-        amountNeeded: Math.round(amount * 100), // Convert to cents/stroops
+        amountNeeded: toTokenUnits(amount, DEFAULT_TOKEN_DECIMALS), // Convert to donation token units (10^decimals per unit)
         imageUrls
       }
       console.log('📤 Sending request to API:', {
@@ -224,6 +237,8 @@ export default function CreateNeedPage() {
         setTitle("")
         setDescription("")
         setLocation("")
+        setCountryCode("CO")
+        setRegion("")
         setCategory("")
         setAmountNeeded("")
         setImages([])
@@ -336,6 +351,29 @@ export default function CreateNeedPage() {
                   </Select>
                 </div>
               </div>
+              <div className="grid grid-cols-1 md:grid-cols-2 gap-6">
+                <div className="space-y-2">
+                  <Label htmlFor="country">Country Code *</Label>
+                  <Input
+                    id="country"
+                    value={countryCode}
+                    onChange={(e) => setCountryCode(e.target.value.toUpperCase())}
+                    placeholder="e.g., CO"
+                    maxLength={2}
+                    required
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="region">Region / Department *</Label>
+                  <Input
+                    id="region"
+                    value={region}
+                    onChange={(e) => setRegion(e.target.value)}
+                    placeholder="e.g., Antioquia"
+                    required
+                  />
+                </div>
+              </div>
               <div className="space-y-2">
                 <Label htmlFor="amount">Amount Needed (USD) *</Label>
                 <Input
@@ -15,6 +15,7 @@ import { useWallet } from "@/contexts/WalletContext"
 import { useAuth } from "@/contexts/AuthContext"
 import { uploadReportImages, generateTempReportId } from "@/lib/firebase-storage"
 import { toast } from "sonner"
+import { fromTokenUnits, toTokenUnits } from "@/lib/amounts"
 import { PinProtectedAction } from "@/components/pin-protected-action"
 
 interface Need {
@@ -25,6 +26,7 @@ interface Need {
   category: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
   imageUrls: string[]
@@ -104,7 +106,7 @@ export default function EditNeedPage({ params }: { params: Promise<{ id: string
           setDescription(needData.description || "")
           setLocation(needData.location || "")
           setCategory(needData.category || "")
-          setAmountNeeded((needData.amountNeeded / 100).toString()) // Convert from cents
+          setAmountNeeded(fromTokenUnits(needData.amountNeeded, needData.decimals).toString()) // Convert from token units
           setImagePreviews(needData.imageUrls || [])
         } else {
           console.error('❌ Failed to fetch need:', data.error)
@@ -207,8 +209,8 @@ export default function EditNeedPage({ params }: { params: Promise<{ id: string
       if (location !== need?.location) updateData.location = location
       if (category !== need?.category) updateData.category = category
       
-      const newAmountCents = Math.round(parseFloat(amountNeeded) * 100)
-      if (newAmountCents !== need?.amountNeeded) updateData.amountNeeded = newAmountCents
+      const newAmountUnits = toTokenUnits(parseFloat(amountNeeded), need?.decimals)
+      if (newAmountUnits !== need?.amountNeeded) updateData.amountNeeded = newAmountUnits
       
       if (JSON.stringify(allImageUrls) !== JSON.stringify(need?.imageUrls)) {
         updateData.imageUrls = allImageUrls
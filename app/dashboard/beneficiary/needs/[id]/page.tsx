@@ -10,6 +10,7 @@ import { Progress } from "@/components/ui/progress"
 import { ChangeHistoryList } from "@/app/components/beneficiary/change-history-list"
 import { useState, useEffect, use } from "react"
 import { toast } from "sonner"
+import { fromTokenUnits } from "@/lib/amounts"
 
 interface Need {
   id: string
@@ -19,6 +20,7 @@ interface Need {
   category: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
   imageUrls: string[]
@@ -172,10 +174,10 @@ export default function NeedDetailPage({ params }: { params: Promise<{ id: strin
                     </div>
                     <div className="flex justify-between text-lg">
                       <span className="font-bold text-teal-600 dark:text-teal-400">
-                        ${(need.amountRaised / 100).toLocaleString()}
+                        ${fromTokenUnits(need.amountRaised, need.decimals).toLocaleString()}
                       </span>
                       <span className="text-muted-foreground">
-                        raised of ${(need.amountNeeded / 100).toLocaleString()}
+                        raised of ${fromTokenUnits(need.amountNeeded, need.decimals).toLocaleString()}
                       </span>
                     </div>
                   </div>
@@ -17,6 +17,7 @@ type Need = {
   category: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
   imageUrls: string[]
@@ -22,6 +22,7 @@ interface ApiNeed {
   category: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
   imageUrls: string[]
@@ -38,6 +39,7 @@ interface DonorNeedDetails {
   place: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   walletAddress: string
   status: string
   author: string
@@ -125,6 +127,7 @@ export default function DonorNeedDetailPage({ params }: { params: Promise<{ id:
       place: apiNeed.location,
       amountNeeded: apiNeed.amountNeeded,
       amountRaised: apiNeed.amountRaised,
+      decimals: apiNeed.decimals,
       walletAddress: apiNeed.creator,
       status: apiNeed.status,
       author: userData?.name || apiNeed.creator.substring(0, 10) + '...', // Use real name or truncated wallet
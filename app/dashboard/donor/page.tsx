@@ -14,6 +14,7 @@ type Need = {
   place: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: string
   imageUrl: string
   type: string
@@ -27,6 +28,7 @@ type ApiNeed = {
   category: string
   amountNeeded: number
   amountRaised: number
+  decimals: number
   status: "Pending" | "Verified" | "Funded"
   imageUrl: string
   imageUrls: string[]
@@ -52,6 +54,7 @@ export default function DonorDashboard() {
       place: apiNeed.location,
       amountNeeded: apiNeed.amountNeeded,
       amountRaised: apiNeed.amountRaised,
+      decimals: apiNeed.decimals,
       status: apiNeed.status,
       imageUrl: apiNeed.imageUrl,
       type: apiNeed.category
@@ -0,0 +1,51 @@
+use soroban_sdk::{contracterror, panic_with_error, token, Address, Env};
+
+/// Typed failures for monetary arithmetic
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AmountError {
+    Overflow = 1,
+    Negative = 2,
+}
+
+/// Decimals used when no donation token is configured (Stellar asset default)
+pub const DEFAULT_DECIMALS: u32 = 7;
+
+/// Reject a negative amount
+pub fn non_negative(env: &Env, amount: i128) -> i128 {
+    if amount < 0 {
+        panic_with_error!(env, AmountError::Negative);
+    }
+    amount
+}
+
+/// Add two amounts, failing on overflow
+pub fn add(env: &Env, a: i128, b: i128) -> i128 {
+    a.checked_add(b).unwrap_or_else(|| panic_with_error!(env, AmountError::Overflow))
+}
+
+/// Subtract two amounts, failing on overflow or a negative result
+pub fn sub(env: &Env, a: i128, b: i128) -> i128 {
+    let result = a.checked_sub(b).unwrap_or_else(|| panic_with_error!(env, AmountError::Overflow));
+    non_negative(env, result)
+}
+
+/// Compute `amount * numerator / denominator` rounded down, failing on overflow
+pub fn mul_div(env: &Env, amount: i128, numerator: i128, denominator: i128) -> i128 {
+    if denominator == 0 {
+        return 0;
+    }
+    amount
+        .checked_mul(numerator)
+        .unwrap_or_else(|| panic_with_error!(env, AmountError::Overflow))
+        / denominator
+}
+
+/// Decimals of the donation token, or the default when none is configured
+pub fn token_decimals(env: &Env, token: Option<Address>) -> u32 {
+    match token {
+        Some(token) => token::Client::new(env, &token).decimals(),
+        None => DEFAULT_DECIMALS,
+    }
+}
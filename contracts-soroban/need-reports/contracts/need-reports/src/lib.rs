@@ -1,26 +1,405 @@
 #![no_std]
+// Contract entry points take one argument per field, and the generated clients mirror them
+#![allow(clippy::too_many_arguments)]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short,
-    Address, Env, String, Vec, Symbol
+    contract, contractimpl, contracttype, symbol_short, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Val, Vec, Symbol
 };
 
+mod amount;
+mod qf;
+mod reputation;
+mod ttl;
+pub use amount::AmountError;
+pub use reputation::{Reputation, ReputationRecord};
+pub use ttl::{DataClass, ReportExpiry, TtlPolicy};
+
 /// Data structure for a need report
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct NeedReport {
     pub id: u64,
     pub creator: Address,
+    pub beneficiary: Address, // Same as creator unless filed by an organization
+    pub organization_id: Option<u64>,
+    pub campaign_id: Option<u64>,
     pub title: String,
     pub description: String,
     pub location: String,
+    pub geo: GeoLocation,
     pub category: String,
-    pub amount_needed: u64,
-    pub amount_raised: u64,
-    pub status: String, // "pending", "verified", "funded", "completed", "rejected"
+    pub amount_needed: i128,
+    pub amount_raised: i128,
+    pub amount_released: i128,
+    pub decimals: u32, // Decimals of the donation token the amounts are denominated in
+    pub status: String, // "pending", "verified", "funded", "completed", "rejected", "cancelled"
     pub created_at: u64,
     pub updated_at: u64,
     pub image_urls: Vec<String>, // Firebase Storage URLs
     pub verification_notes: String,
+    pub tags: Vec<Symbol>,
+    pub deadline: Option<u64>, // Timestamp by which the need must be met, if any
+    pub urgency: String, // "low", "medium", "high", "critical"; set by verifiers
+}
+
+/// Structured location used for region and proximity queries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeoLocation {
+    pub country_code: String, // ISO 3166-1 alpha-2, e.g. "CO"
+    pub region: String, // State / department, e.g. "Antioquia"
+    pub latitude_e6: Option<i64>, // Degrees * 1_000_000
+    pub longitude_e6: Option<i64>, // Degrees * 1_000_000
+    pub geohash: Option<String>, // Base32 geohash, up to 12 characters
+}
+
+/// Field changes to a report; `None` leaves the field untouched
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportChanges {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub geo: GeoChange,
+    pub category: Option<String>,
+    pub amount_needed: Option<i128>,
+    pub image_urls: Option<Vec<String>>,
+}
+
+/// Structured location change (`Option` of a custom type can't be nested in a struct)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GeoChange {
+    Unchanged,
+    Set(GeoLocation),
+}
+
+/// Data structure for a proposed change to a locked report
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Amendment {
+    pub id: u32, // Position in the report's amendment history
+    pub report_id: u64,
+    pub proposer: Address,
+    pub changes: ReportChanges,
+    pub reason: String,
+    pub status: String, // "pending", "approved", "rejected"
+    pub proposed_at: u64,
+    pub reviewed_by: Option<Address>,
+    pub reviewed_at: u64,
+    pub review_notes: String,
+}
+
+/// Identity verification recorded for a beneficiary address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryIdentity {
+    pub address: Address,
+    pub level: String, // "unverified", "social_verified", "ngo_verified"
+    pub evidence_hash: BytesN<32>, // Hash of the off-chain verification evidence
+    pub verified_by: Address,
+    pub updated_at: u64,
+}
+
+/// Report limits applied to beneficiaries at a given identity level
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelLimits {
+    pub level: String,
+    pub max_amount_needed: i128,
+    pub max_open_reports: u32,
+}
+
+/// Organization (NGO) account that files reports on behalf of beneficiaries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: u64,
+    pub name: String,
+    pub admins: Vec<Address>, // Manage members and payout settings
+    pub members: Vec<Address>, // Can file reports for the organization
+    pub payout_address: Address,
+    pub payout_to_beneficiary: bool, // Otherwise payouts go to payout_address
+    pub created_at: u64,
+}
+
+/// Data structure for a donation held in escrow
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Donation {
+    pub report_id: u64,
+    pub donor: Address,
+    pub amount: i128, // Credited to the report after the platform fee
+    pub fee: i128,
+    pub tip: i128,
+    pub refunded: i128,
+    pub timestamp: u64,
+}
+
+/// Platform fee charged on donations and sent to the treasury
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32, // Basis points of each donation, at most MAX_FEE_BPS
+    pub treasury: Address,
+}
+
+/// Authorization for another address to manage a creator's reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    pub grantor: Address, // Report creator granting the permissions
+    pub delegate: Address,
+    pub report_id: Option<u64>, // None covers all of the grantor's reports
+    pub permissions: u32, // Bitmask of DELEGATE_* flags
+    pub expires_at: u64, // Ledger timestamp after which the delegation is void
+    pub created_at: u64,
+}
+
+/// Challenge raised against a report suspected of fraud
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub id: u32, // Position in the report's dispute history
+    pub report_id: u64,
+    pub reporter: Address,
+    pub reason: String,
+    pub evidence_hash: BytesN<32>, // Hash of the off-chain evidence
+    pub status: String, // "open", "upheld", "dismissed"
+    pub previous_status: String, // Report status before it was disputed
+    pub created_at: u64,
+    pub resolved_by: Option<Address>,
+    pub resolved_at: u64,
+    pub resolution_notes: String,
+}
+
+/// Single line of a completion report's spend breakdown
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendItem {
+    pub description: String,
+    pub amount: i128,
+    pub receipt_hashes: Vec<BytesN<32>>, // Hashes of off-chain receipts
+}
+
+/// Proof of delivery submitted by the creator once funds have been released
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionReport {
+    pub report_id: u64,
+    pub submitted_by: Address,
+    pub evidence: Vec<String>, // Photo / document URLs
+    pub spend_breakdown: Vec<SpendItem>,
+    pub status: String, // "submitted", "confirmed", "rejected"
+    pub submitted_at: u64,
+    pub reviewed_by: Option<Address>,
+    pub reviewed_at: u64,
+    pub review_notes: String,
+}
+
+/// Sponsor-funded pool that matches qualifying donations
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPool {
+    pub id: u64,
+    pub sponsor: Address,
+    pub token: Address,
+    pub balance: i128,
+    pub ratio_bps: u32, // Matched per donated unit, 10000 = 1:1
+    pub category: Option<String>, // Only match reports in this category
+    pub country_code: Option<String>, // Only match reports in this country
+    pub region: Option<String>, // Only match reports in this region
+    pub cap_per_report: i128,
+    pub urgency_weighted: bool, // Scale matches by the report's urgency
+    pub expires_at: u64,
+    pub created_at: u64,
+}
+
+/// Quadratic funding round distributing a pool across eligible reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfRound {
+    pub id: u64,
+    pub title: String,
+    pub organizer: Address,
+    pub pool_amount: i128,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub report_ids: Vec<u64>, // Eligible reports
+    pub finalized: bool,
+    pub created_at: u64,
+}
+
+/// Matching a report received when a quadratic funding round was finalized
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QfAllocation {
+    pub report_id: u64,
+    pub unique_donors: u32,
+    pub total_contributed: i128,
+    pub matched: i128,
+}
+
+/// What a recurring donation is sent to each period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubscriptionTarget {
+    Report(u64),
+    Beneficiary(Address), // Oldest open report of the beneficiary still needing funds
+    Category(String), // Oldest open report in the category still needing funds
+}
+
+/// Recurring donation pulled from the donor's token allowance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Subscription {
+    pub id: u64,
+    pub donor: Address,
+    pub target: SubscriptionTarget,
+    pub amount: i128,
+    pub period_secs: u64,
+    pub next_due: u64,
+    pub payments_made: u32,
+    pub active: bool,
+    pub created_at: u64,
+}
+
+/// What happens to the part of a donation beyond a report's remaining need
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OverfundingPolicy {
+    Accept, // Default: amount_raised may exceed amount_needed
+    Reject, // Donations beyond the remaining need fail
+    Refund, // The excess is returned to the donor
+    RedirectToCampaign, // The excess is split across the report's campaign, else the general fund
+    RedirectToGeneralFund,
+}
+
+/// How a campaign-level donation is divided across member reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SplitRule {
+    Equal,
+    ProportionalToRemaining, // Weighted by amount_needed - amount_raised
+    UrgencyWeighted, // Weighted by the report's urgency
+}
+
+/// Group of related reports, e.g. for one disaster response
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Campaign {
+    pub id: u64,
+    pub title: String,
+    pub organizer: Address,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub country_code: String,
+    pub region: String,
+    pub category: String,
+    pub split_rule: SplitRule,
+    pub report_ids: Vec<u64>,
+    pub total_donated: i128, // Received through campaign-level donations
+    pub created_at: u64,
+}
+
+/// Aggregate figures across a campaign's member reports
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignStats {
+    pub campaign_id: u64,
+    pub total_reports: u64,
+    pub funded_reports: u64,
+    pub completed_reports: u64,
+    pub total_amount_needed: i128,
+    pub total_amount_raised: i128,
+    pub total_donated: i128,
+}
+
+/// Criteria for `search_reports`; unset fields match everything
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportFilter {
+    pub status: Option<String>,
+    pub category: Option<String>,
+    pub tags: Vec<Symbol>, // Reports must carry every listed tag
+    pub creator: Option<Address>,
+    pub min_amount: Option<i128>, // Bounds on amount_needed, inclusive
+    pub max_amount: Option<i128>,
+    pub created_after: Option<u64>, // Bounds on created_at, inclusive
+    pub created_before: Option<u64>,
+}
+
+/// One page of search results
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportPage {
+    pub reports: Vec<NeedReport>,
+    pub next_cursor: Option<u64>, // Pass back to continue; None once every report was scanned
+}
+
+/// Orderings offered by `get_reports_sorted`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SortKey {
+    RemainingAmount, // Smallest amount still needed first
+    PercentFunded, // Closest to fully funded first
+    Deadline, // Soonest deadline first; reports without one are left out
+    Recency, // Newest first
+    Priority, // Most urgent first, oldest first within an urgency level
+}
+
+/// Directory entry for one page of a ranked index
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct SortedPage {
+    first: (i128, u64), // Smallest (value, report ID) on the page
+    page_id: u32,
+    len: u32,
+}
+
+/// Pages of a ranked index in ascending order
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct SortedDirectory {
+    pages: Vec<SortedPage>,
+    next_page_id: u32,
+}
+
+/// A verifier's confirmation that they saw plaintext details matching a commitment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DetailsAttestation {
+    pub verifier: Address,
+    pub timestamp: u64,
+}
+
+/// Sensitive details of a private report, kept off the public report record
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivateDetails {
+    pub report_id: u64,
+    pub commitment: BytesN<32>, // sha256 of the salted plaintext details
+    pub envelopes: Map<Address, Bytes>, // Details encrypted to each verifier's public key
+    pub attestations: Vec<DetailsAttestation>,
+}
+
+/// Compact record kept for a closed report once its full state is archived
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedReport {
+    pub id: u64,
+    pub creator: Address,
+    pub beneficiary: Address,
+    pub category: String,
+    pub amount_needed: i128,
+    pub amount_raised: i128,
+    pub amount_released: i128,
+    pub decimals: u32,
+    pub final_status: String,
+    pub created_at: u64,
+    pub archived_at: u64,
+    pub log_entries: u32,
+    pub log_head_hash: BytesN<32>, // SHA-256 of the XDR-encoded change log at archive time
 }
 
 /// Data structure for tracking changes (for transparency)
@@ -45,14 +424,110 @@ pub struct ReportStats {
     pub verified_reports: u64,
     pub funded_reports: u64,
     pub completed_reports: u64,
-    pub total_amount_needed: u64,
-    pub total_amount_raised: u64,
+    pub total_amount_needed: i128,
+    pub total_amount_raised: i128,
+    pub total_fees_collected: i128,
+    pub total_tips_collected: i128,
+    pub category_stats: Vec<CategoryStats>,
+}
+
+/// Data structure for a registered report category
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Category {
+    pub id: String, // Stable key stored in NeedReport.category, e.g. "food"
+    pub display_name: String,
+    pub deprecated: bool,
+    pub created_at: u64,
 }
 
+/// Per-category totals included in the platform statistics
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryStats {
+    pub category: String,
+    pub report_count: u64,
+    pub amount_needed: i128,
+    pub amount_raised: i128,
+}
+
+/// Delegate permission flags
+pub const DELEGATE_UPDATE_REPORT: u32 = 1;
+pub const DELEGATE_PROPOSE_AMENDMENT: u32 = 1 << 1;
+
+/// Pause scopes for the emergency circuit breaker
+pub const PAUSE_CREATIONS: u32 = 1; // Report creation, edits and amendments
+pub const PAUSE_DONATIONS: u32 = 1 << 1;
+pub const PAUSE_PAYOUTS: u32 = 1 << 2; // Every transfer out of the contract: releases, refunds and pool returns
+pub const PAUSE_ALL: u32 = PAUSE_CREATIONS | PAUSE_DONATIONS | PAUSE_PAYOUTS;
+
+/// Upper bound for the platform fee (5%)
+pub const MAX_FEE_BPS: u32 = 500;
+
+/// Tag limits per report
+pub const MAX_TAGS: u32 = 8;
+
+/// Reports examined by a single `search_reports` call, matching or not
+pub const SEARCH_SCAN_LIMIT: u32 = 200;
+
+/// Largest ID range a single `bump_range` call may cover
+pub const MAX_BUMP_RANGE: u64 = 50;
+
+/// Entries per page of a ranked index; a page splits in half once it grows past this
+pub const SORT_PAGE_SIZE: u32 = 64;
+
 /// Storage keys
 const NEXT_REPORT_ID: Symbol = symbol_short!("NEXT_ID");
 const REPORTS_COUNT: Symbol = symbol_short!("COUNT");
 const ADMIN_LIST: Symbol = symbol_short!("ADMINS");
+const VERIFIER_LIST: Symbol = symbol_short!("VERIFIERS");
+const CATEGORIES: Symbol = symbol_short!("CATS");
+const COUNTRY_INDEX: Symbol = symbol_short!("COUNTRY");
+const REGION_INDEX: Symbol = symbol_short!("REGION");
+const GEOHASH_INDEX: Symbol = symbol_short!("GEOHASH");
+const AMENDMENTS: Symbol = symbol_short!("AMEND");
+const IDENTITIES: Symbol = symbol_short!("IDENTITY");
+const LEVEL_LIMITS: Symbol = symbol_short!("LIMITS");
+const OPEN_REPORTS: Symbol = symbol_short!("OPEN");
+const NEXT_ORG_ID: Symbol = symbol_short!("NEXT_ORG");
+const ORGANIZATIONS: Symbol = symbol_short!("ORG");
+const DONATION_TOKEN: Symbol = symbol_short!("TOKEN");
+const DONATIONS: Symbol = symbol_short!("DONATIONS");
+const ESCROW: Symbol = symbol_short!("ESCROW");
+const DELEGATIONS: Symbol = symbol_short!("DELEGATES");
+const DISPUTES: Symbol = symbol_short!("DISPUTES");
+const COMPLETIONS: Symbol = symbol_short!("COMPLETE");
+const ATTESTERS: Symbol = symbol_short!("ATTESTER");
+const PAUSED: Symbol = symbol_short!("PAUSED");
+const FEE_CONFIG: Symbol = symbol_short!("FEES");
+const TOTAL_FEES: Symbol = symbol_short!("FEE_TOTAL");
+const TOTAL_TIPS: Symbol = symbol_short!("TIP_TOTAL");
+const NEXT_POOL_ID: Symbol = symbol_short!("NEXT_POOL");
+const ACTIVE_POOLS: Symbol = symbol_short!("POOLS");
+const POOLS: Symbol = symbol_short!("POOL");
+const POOL_MATCHED: Symbol = symbol_short!("MATCHED");
+const NEXT_ROUND_ID: Symbol = symbol_short!("NEXT_QF");
+const QF_ROUNDS: Symbol = symbol_short!("QF");
+const QF_REPORT_ROUNDS: Symbol = symbol_short!("QF_RPT");
+const QF_CONTRIBUTIONS: Symbol = symbol_short!("QF_CONTR");
+const QF_RESULTS: Symbol = symbol_short!("QF_RESULT");
+const NEXT_SUBSCRIPTION_ID: Symbol = symbol_short!("NEXT_SUB");
+const SUBSCRIPTIONS: Symbol = symbol_short!("SUB");
+const DONOR_SUBSCRIPTIONS: Symbol = symbol_short!("DONOR_SUB");
+const NEXT_CAMPAIGN_ID: Symbol = symbol_short!("NEXT_CAMP");
+const CAMPAIGNS: Symbol = symbol_short!("CAMPAIGN");
+const OVERFUNDING: Symbol = symbol_short!("OVERFUND");
+const GENERAL_FUND: Symbol = symbol_short!("GEN_FUND");
+const ARCHIVED: Symbol = symbol_short!("ARCHIVED");
+const TAG_INDEX: Symbol = symbol_short!("TAG");
+const SORTED_INDEX: Symbol = symbol_short!("SORTED");
+const SORTED_PAGE: Symbol = symbol_short!("SORT_PG");
+const PRIVATE_DETAILS: Symbol = symbol_short!("PRIVATE");
+
+/// Geohash prefixes up to this length are indexed (~1.2km x 0.6km cells)
+const GEOHASH_INDEX_PRECISION: u32 = 6;
+const GEOHASH_MAX_LEN: u32 = 12;
+const GEOHASH_ALPHABET: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
 
 #[contract]
 pub struct NeedReportsContract;
@@ -72,7 +547,7 @@ impl NeedReportsContract {
         env.storage().instance().set(&ADMIN_LIST, &admins);
         
         // Extend TTL
-        env.storage().instance().extend_ttl(5184000, 5184000); // ~60 days
+        ttl::extend_instance(&env);
     }
 
     /// Create a new need report
@@ -82,180 +557,284 @@ impl NeedReportsContract {
         title: String,
         description: String,
         location: String,
+        geo: GeoLocation,
         category: String,
-        amount_needed: u64,
+        amount_needed: i128,
         image_urls: Vec<String>,
     ) -> u64 {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
+        
         // Authenticate the creator
         creator.require_auth();
         
-        // Get next report ID
-        let report_id = env.storage().instance().get::<Symbol, u64>(&NEXT_REPORT_ID).unwrap_or(1);
-        
-        // Create new report
         let report = NeedReport {
-            id: report_id,
+            id: 0,
             creator: creator.clone(),
-            title: title.clone(),
+            beneficiary: creator,
+            organization_id: None,
+            campaign_id: None,
+            title,
             description,
             location,
+            geo,
             category,
             amount_needed,
             amount_raised: 0,
+            amount_released: 0,
+            decimals: amount::token_decimals(&env, Self::get_donation_token(env.clone())),
             status: String::from_str(&env, "pending"),
             created_at: env.ledger().timestamp(),
             updated_at: env.ledger().timestamp(),
             image_urls,
             verification_notes: String::from_str(&env, ""),
+            tags: Vec::new(&env),
+            deadline: None,
+            urgency: String::from_str(&env, "medium"),
         };
         
-        // Store the report using report_id as key
-        env.storage().persistent().set(&report_id, &report);
-        env.storage().persistent().extend_ttl(&report_id, 5184000, 5184000);
+        Self::insert_report(&env, report)
+    }
+
+    /// Create a need report filed by an organization member on behalf of a beneficiary
+    pub fn create_report_on_behalf(
+        env: Env,
+        member: Address,
+        organization_id: u64,
+        beneficiary: Address,
+        title: String,
+        description: String,
+        location: String,
+        geo: GeoLocation,
+        category: String,
+        amount_needed: i128,
+        image_urls: Vec<String>,
+    ) -> u64 {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
         
-        // Update counters
-        env.storage().instance().set(&NEXT_REPORT_ID, &(report_id + 1));
-        let current_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
-        env.storage().instance().set(&REPORTS_COUNT, &(current_count + 1));
+        member.require_auth();
         
-        // Log the creation
-        Self::log_change(
-            env.clone(),
-            report_id,
-            creator,
-            String::from_str(&env, "created"),
-            String::from_str(&env, ""),
-            title,
-            String::from_str(&env, "Initial report creation"),
-        );
+        if !Self::is_org_member(env.clone(), organization_id, member.clone()) {
+            panic!("Unauthorized: Only organization members can file reports for it");
+        }
         
-        // Extend instance TTL
-        env.storage().instance().extend_ttl(5184000, 5184000);
+        let report = NeedReport {
+            id: 0,
+            creator: member,
+            beneficiary,
+            organization_id: Some(organization_id),
+            campaign_id: None,
+            title,
+            description,
+            location,
+            geo,
+            category,
+            amount_needed,
+            amount_raised: 0,
+            amount_released: 0,
+            decimals: amount::token_decimals(&env, Self::get_donation_token(env.clone())),
+            status: String::from_str(&env, "pending"),
+            created_at: env.ledger().timestamp(),
+            updated_at: env.ledger().timestamp(),
+            image_urls,
+            verification_notes: String::from_str(&env, ""),
+            tags: Vec::new(&env),
+            deadline: None,
+            urgency: String::from_str(&env, "medium"),
+        };
         
-        report_id
+        Self::insert_report(&env, report)
     }
 
-    /// Get a specific report by ID
-    pub fn get_report(env: Env, report_id: u64) -> Option<NeedReport> {
-        if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
-            // Extend TTL when accessed
-            env.storage().persistent().extend_ttl(&report_id, 5184000, 5184000);
-            Some(report)
-        } else {
-            None
-        }
+    /// Create a need report whose sensitive details are only committed to on chain
+    ///
+    /// The public record carries a coarse location (country and region, no coordinates)
+    /// and an empty description; `commitment` is the sha256 of the salted plaintext details.
+    pub fn create_private_report(
+        env: Env,
+        creator: Address,
+        title: String,
+        coarse_location: String,
+        country_code: String,
+        region: String,
+        category: String,
+        amount_needed: i128,
+        commitment: BytesN<32>,
+        image_urls: Vec<String>,
+    ) -> u64 {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
+        
+        creator.require_auth();
+        
+        let report = NeedReport {
+            id: 0,
+            creator: creator.clone(),
+            beneficiary: creator,
+            organization_id: None,
+            campaign_id: None,
+            title,
+            description: String::from_str(&env, ""),
+            location: coarse_location,
+            geo: GeoLocation {
+                country_code,
+                region,
+                latitude_e6: None,
+                longitude_e6: None,
+                geohash: None,
+            },
+            category,
+            amount_needed,
+            amount_raised: 0,
+            amount_released: 0,
+            decimals: amount::token_decimals(&env, Self::get_donation_token(env.clone())),
+            status: String::from_str(&env, "pending"),
+            created_at: env.ledger().timestamp(),
+            updated_at: env.ledger().timestamp(),
+            image_urls,
+            verification_notes: String::from_str(&env, ""),
+            tags: Vec::new(&env),
+            deadline: None,
+            urgency: String::from_str(&env, "medium"),
+        };
+        let report_id = Self::insert_report(&env, report);
+        
+        let details = PrivateDetails {
+            report_id,
+            commitment,
+            envelopes: Map::new(&env),
+            attestations: Vec::new(&env),
+        };
+        Self::save_private_details(&env, &details);
+        
+        report_id
     }
 
-    /// Update a report (only by creator or admin)
-    pub fn update_report(
+    /// Store the report's details encrypted to a verifier's public key (creator only)
+    pub fn add_details_envelope(
         env: Env,
         report_id: u64,
-        updater: Address,
-        title: Option<String>,
-        description: Option<String>,
+        creator: Address,
+        verifier: Address,
+        envelope: Bytes,
+    ) -> bool {
+        creator.require_auth();
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.creator != creator {
+            panic!("Unauthorized: Only the creator can share report details");
+        }
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Details can only be shared with verifiers");
+        }
+        let Some(mut details) = Self::get_private_details(env.clone(), report_id) else {
+            panic!("Report has no private details");
+        };
+        
+        details.envelopes.set(verifier, envelope);
+        Self::save_private_details(&env, &details);
+        true
+    }
+
+    /// Attest to having seen plaintext details that hash to the report's commitment (verifier only)
+    pub fn attest_details(env: Env, report_id: u64, verifier: Address, commitment: BytesN<32>) -> bool {
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can attest report details");
+        }
+        let Some(mut details) = Self::get_private_details(env.clone(), report_id) else {
+            return false;
+        };
+        if details.commitment != commitment {
+            panic!("Details do not match the report's commitment");
+        }
+        if details.attestations.iter().any(|attestation| attestation.verifier == verifier) {
+            panic!("Verifier has already attested these details");
+        }
+        
+        details.attestations.push_back(DetailsAttestation {
+            verifier,
+            timestamp: env.ledger().timestamp(),
+        });
+        Self::save_private_details(&env, &details);
+        true
+    }
+
+    /// Get the commitment, verifier envelopes and attestations of a private report
+    pub fn get_private_details(env: Env, report_id: u64) -> Option<PrivateDetails> {
+        env.storage().persistent().get::<(Symbol, u64), PrivateDetails>(&(PRIVATE_DETAILS, report_id))
+    }
+
+    /// Get a specific report by ID
+    pub fn get_report(env: Env, report_id: u64) -> Option<NeedReport> {
+        if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+            // Extend TTL when accessed
+            ttl::extend_report(&env, report_id);
+            Some(report)
+        } else {
+            None
+        }
+    }
+
+    /// Update a report (only by creator or admin)
+    pub fn update_report(
+        env: Env,
+        report_id: u64,
+        updater: Address,
+        title: Option<String>,
+        description: Option<String>,
         location: Option<String>,
+        geo: Option<GeoLocation>,
         category: Option<String>,
-        amount_needed: Option<u64>,
+        amount_needed: Option<i128>,
         image_urls: Option<Vec<String>>,
         reason: String,
     ) -> bool {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
+        
         // Authenticate the updater
         updater.require_auth();
         
         if let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
-            // Check if updater is creator or admin
+            // Check if updater is creator, admin or an authorized delegate
             let is_creator = report.creator == updater;
             let is_admin = Self::is_admin(env.clone(), updater.clone());
+            let is_delegate = Self::is_delegate(env.clone(), report_id, updater.clone(), DELEGATE_UPDATE_REPORT);
             
-            if !is_creator && !is_admin {
-                panic!("Unauthorized: Only creator or admin can update report");
-            }
-            
-            // Update fields and log changes
-            if let Some(new_title) = title {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "title"),
-                    report.title.clone(),
-                    new_title.clone(),
-                    reason.clone(),
-                );
-                report.title = new_title;
-            }
-            
-            if let Some(new_description) = description {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "description"),
-                    String::from_str(&env, "[description_updated]"),
-                    String::from_str(&env, "[new_description]"),
-                    reason.clone(),
-                );
-                report.description = new_description;
-            }
-            
-            if let Some(new_location) = location {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "location"),
-                    report.location.clone(),
-                    new_location.clone(),
-                    reason.clone(),
-                );
-                report.location = new_location;
-            }
-            
-            if let Some(new_category) = category {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "category"),
-                    report.category.clone(),
-                    new_category.clone(),
-                    reason.clone(),
-                );
-                report.category = new_category;
+            if !is_creator && !is_admin && !is_delegate {
+                panic!("Unauthorized: Only creator, admin or delegate can update report");
             }
             
-            if let Some(new_amount) = amount_needed {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "amount_needed"),
-                    String::from_str(&env, "amount_updated"),
-                    String::from_str(&env, "new_amount"),
-                    reason.clone(),
-                );
-                report.amount_needed = new_amount;
+            // Core fields are frozen once a verifier has vouched for the report
+            if Self::is_locked_status(&env, &report.status) {
+                panic!("Report is locked: changes after verification require an approved amendment");
             }
             
-            if let Some(new_images) = image_urls {
-                Self::log_change(
-                    env.clone(),
-                    report_id,
-                    updater.clone(),
-                    String::from_str(&env, "images"),
-                    String::from_str(&env, "[images_updated]"),
-                    String::from_str(&env, "[new_images]"),
-                    reason.clone(),
-                );
-                report.image_urls = new_images;
-            }
+            Self::apply_changes(
+                &env,
+                &mut report,
+                &updater,
+                ReportChanges {
+                    title,
+                    description,
+                    location,
+                    geo: match geo {
+                        Some(new_geo) => GeoChange::Set(new_geo),
+                        None => GeoChange::Unchanged,
+                    },
+                    category,
+                    amount_needed,
+                    image_urls,
+                },
+                &reason,
+            );
             
             // Update timestamp
             report.updated_at = env.ledger().timestamp();
             
             // Save updated report
-            env.storage().persistent().set(&report_id, &report);
-            env.storage().persistent().extend_ttl(&report_id, 5184000, 5184000);
+            Self::save_report(&env, &report);
             
             true
         } else {
@@ -263,7 +842,128 @@ impl NeedReportsContract {
         }
     }
 
-    /// Update report status (admin only)
+    /// Propose changes to a locked report (creator only); applied on verifier approval
+    pub fn propose_amendment(
+        env: Env,
+        report_id: u64,
+        creator: Address,
+        changes: ReportChanges,
+        reason: String,
+    ) -> u32 {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
+        
+        creator.require_auth();
+        
+        let report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        
+        if report.creator != creator
+            && !Self::is_delegate(env.clone(), report_id, creator.clone(), DELEGATE_PROPOSE_AMENDMENT)
+        {
+            panic!("Unauthorized: Only creator or delegate can propose amendments");
+        }
+        Self::require_amendable(&env, &report);
+        
+        if Self::get_pending_amendment(env.clone(), report_id).is_some() {
+            panic!("An amendment is already pending review");
+        }
+        
+        // Validate up front so verifiers only review applicable changes
+        if let GeoChange::Set(geo) = &changes.geo {
+            Self::validate_geo_location(geo);
+        }
+        if let Some(category) = &changes.category {
+            Self::require_active_category(&env, category);
+        }
+        
+        let mut amendments = Self::get_amendments(env.clone(), report_id);
+        let amendment_id = amendments.len();
+        amendments.push_back(Amendment {
+            id: amendment_id,
+            report_id,
+            proposer: creator,
+            changes,
+            reason,
+            status: String::from_str(&env, "pending"),
+            proposed_at: env.ledger().timestamp(),
+            reviewed_by: None,
+            reviewed_at: 0,
+            review_notes: String::from_str(&env, ""),
+        });
+        
+        let key = (AMENDMENTS, report_id);
+        env.storage().persistent().set(&key, &amendments);
+        ttl::extend(&env, &key, DataClass::Record);
+        
+        amendment_id
+    }
+
+    /// Approve the pending amendment and apply its changes (verifier only)
+    pub fn approve_amendment(env: Env, report_id: u64, verifier: Address, notes: String) -> bool {
+        Self::require_not_paused(&env, PAUSE_CREATIONS);
+        
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can review amendments");
+        }
+        
+        let Some(mut amendment) = Self::get_pending_amendment(env.clone(), report_id) else {
+            return false;
+        };
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        
+        if amendment.proposer == verifier {
+            panic!("Unauthorized: Verifier cannot review their own amendment");
+        }
+        // The report may have been disputed or closed since the amendment was proposed
+        Self::require_amendable(&env, &report);
+        
+        Self::apply_changes(&env, &mut report, &verifier, amendment.changes.clone(), &amendment.reason);
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        
+        amendment.status = String::from_str(&env, "approved");
+        Self::close_amendment(&env, amendment, verifier, notes);
+        true
+    }
+
+    /// Reject the pending amendment, leaving the report unchanged (verifier only)
+    pub fn reject_amendment(env: Env, report_id: u64, verifier: Address, notes: String) -> bool {
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can review amendments");
+        }
+        
+        let Some(mut amendment) = Self::get_pending_amendment(env.clone(), report_id) else {
+            return false;
+        };
+        
+        amendment.status = String::from_str(&env, "rejected");
+        Self::close_amendment(&env, amendment, verifier, notes);
+        true
+    }
+
+    /// Get the amendment awaiting review, if any
+    pub fn get_pending_amendment(env: Env, report_id: u64) -> Option<Amendment> {
+        let pending_status = String::from_str(&env, "pending");
+        Self::get_amendments(env, report_id)
+            .iter()
+            .find(|amendment| amendment.status == pending_status)
+    }
+
+    /// Get all proposed amendments for a report, including reviewed ones
+    pub fn get_amendments(env: Env, report_id: u64) -> Vec<Amendment> {
+        env.storage().persistent()
+            .get::<(Symbol, u64), Vec<Amendment>>(&(AMENDMENTS, report_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Update report status (admin only; verifiers may attest by setting "verified")
     pub fn update_status(
         env: Env,
         report_id: u64,
@@ -273,30 +973,51 @@ impl NeedReportsContract {
     ) -> bool {
         admin.require_auth();
         
-        // Check if admin
-        if !Self::is_admin(env.clone(), admin.clone()) {
+        // Check if admin, or a verifier attesting to the report
+        let is_attestation = new_status == String::from_str(&env, "verified")
+            && Self::is_verifier(env.clone(), admin.clone());
+        if !Self::is_admin(env.clone(), admin.clone()) && !is_attestation {
             panic!("Unauthorized: Only admin can update status");
         }
         
+        // Completion needs a verifier-confirmed proof of delivery
+        if new_status == String::from_str(&env, "completed") {
+            panic!("Reports are completed through confirm_completion");
+        }
+        // Cancellation must go through cancel_report so escrow is refunded
+        if new_status == String::from_str(&env, "cancelled") {
+            panic!("Reports are cancelled through cancel_report");
+        }
+        // Disputes are opened by flag_report and closed by resolve_dispute so their records stay in step
+        let disputed_status = String::from_str(&env, "disputed");
+        if new_status == disputed_status {
+            panic!("Reports are disputed through flag_report");
+        }
+        
         if let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
-            // Log status change
-            Self::log_change(
-                env.clone(),
-                report_id,
-                admin,
-                String::from_str(&env, "status"),
-                report.status.clone(),
-                new_status.clone(),
-                verification_notes.clone(),
-            );
-            
-            report.status = new_status;
+            if report.status == disputed_status {
+                panic!("Disputed reports are resolved through resolve_dispute");
+            }
+            if Self::is_terminal_status(&env, &report.status) {
+                panic!("Closed reports cannot change status");
+            }
+            // Attestation confirms a new report; it can't reopen or re-verify one an admin has moved on
+            if !Self::is_admin(env.clone(), admin.clone()) && report.status != String::from_str(&env, "pending") {
+                panic!("Verifiers can only attest pending reports");
+            }
+            // Private reports are verified against their hidden details
+            if new_status == String::from_str(&env, "verified") {
+                if let Some(details) = Self::get_private_details(env.clone(), report_id) {
+                    if details.attestations.is_empty() {
+                        panic!("Private report details have not been attested");
+                    }
+                }
+            }
+            Self::transition_status(&env, &mut report, &admin, new_status, verification_notes.clone());
             report.verification_notes = verification_notes;
-            report.updated_at = env.ledger().timestamp();
             
             // Save updated report
-            env.storage().persistent().set(&report_id, &report);
-            env.storage().persistent().extend_ttl(&report_id, 5184000, 5184000);
+            Self::save_report(&env, &report);
             
             true
         } else {
@@ -304,13 +1025,44 @@ impl NeedReportsContract {
         }
     }
 
+    /// Withdraw a report before any funds are released; escrowed donations are refunded (creator or beneficiary)
+    pub fn cancel_report(env: Env, report_id: u64, caller: Address, reason: String) -> bool {
+        Self::require_not_paused(&env, PAUSE_PAYOUTS);
+        
+        caller.require_auth();
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.creator != caller && report.beneficiary != caller {
+            panic!("Unauthorized: Only creator or beneficiary can cancel the report");
+        }
+        if Self::is_terminal_status(&env, &report.status) {
+            panic!("Report is already closed");
+        }
+        if report.status == String::from_str(&env, "disputed") {
+            panic!("Report is disputed: wait for the dispute to be resolved");
+        }
+        if report.amount_released > 0 {
+            panic!("Funds have already been released for this report");
+        }
+        
+        Self::transition_status(&env, &mut report, &caller, String::from_str(&env, "cancelled"), reason);
+        Self::save_report(&env, &report);
+        
+        Self::refund_escrow(&env, report_id, &caller);
+        true
+    }
+
     /// Update amount raised (when donations are received)
     pub fn update_amount_raised(
         env: Env,
         report_id: u64,
-        new_amount_raised: u64,
+        new_amount_raised: i128,
         updater: Address,
     ) -> bool {
+        Self::require_not_paused(&env, PAUSE_DONATIONS);
+        
         updater.require_auth();
         
         // Check if admin (only admin can update raised amounts)
@@ -318,7 +1070,16 @@ impl NeedReportsContract {
             panic!("Unauthorized: Only admin can update amount raised");
         }
         
+        amount::non_negative(&env, new_amount_raised);
+        
         if let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+            // Manual updates move no tokens, so only Accept may record an overfunded amount
+            if new_amount_raised > report.amount_needed
+                && Self::get_overfunding_policy(env.clone(), report_id) != OverfundingPolicy::Accept
+            {
+                panic!("Amount raised exceeds amount needed");
+            }
+            
             // Log amount change
             Self::log_change(
                 env.clone(),
@@ -343,8 +1104,7 @@ impl NeedReportsContract {
             }
             
             // Save updated report
-            env.storage().persistent().set(&report_id, &report);
-            env.storage().persistent().extend_ttl(&report_id, 5184000, 5184000);
+            Self::save_report(&env, &report);
             
             true
         } else {
@@ -360,6 +1120,7 @@ impl NeedReportsContract {
         for i in 1..=total_count {
             if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&i) {
                 if report.creator == user {
+                    ttl::extend_report(&env, i);
                     user_reports.push_back(report);
                 }
             }
@@ -401,6 +1162,238 @@ impl NeedReportsContract {
         filtered_reports
     }
 
+    /// Get reports in a country, optionally narrowed to a region
+    pub fn get_reports_by_region(env: Env, country_code: String, region: Option<String>) -> Vec<NeedReport> {
+        let ids = match region {
+            Some(region) => Self::index_get(&env, &(REGION_INDEX, country_code, region)),
+            None => Self::index_get(&env, &(COUNTRY_INDEX, country_code)),
+        };
+        
+        let mut reports = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&id) {
+                reports.push_back(report);
+            }
+        }
+        
+        reports
+    }
+
+    /// Replace a report's tags (creator, verifier or admin)
+    pub fn set_tags(env: Env, report_id: u64, caller: Address, tags: Vec<Symbol>) -> bool {
+        caller.require_auth();
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.creator != caller && !Self::is_verifier(env.clone(), caller.clone()) {
+            panic!("Unauthorized: Only creator or verifier can tag the report");
+        }
+        if tags.len() > MAX_TAGS {
+            panic!("Too many tags");
+        }
+        for (i, tag) in tags.iter().enumerate() {
+            if tags.first_index_of(&tag) != Some(i as u32) {
+                panic!("Duplicate tag");
+            }
+        }
+        
+        for tag in report.tags.iter() {
+            Self::index_remove(&env, &(TAG_INDEX, tag), report_id);
+        }
+        for tag in tags.iter() {
+            Self::index_add(&env, &(TAG_INDEX, tag), report_id);
+        }
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            caller,
+            String::from_str(&env, "tags"),
+            String::from_str(&env, "tags_updated"),
+            String::from_str(&env, "new_tags"),
+            String::from_str(&env, "Tags updated"),
+        );
+        
+        report.tags = tags;
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        true
+    }
+
+    /// Search reports by several criteria, in ID order, starting after `cursor` (0 to start)
+    pub fn search_reports(env: Env, filter: ReportFilter, cursor: u64, limit: u32) -> ReportPage {
+        // Tagged searches walk the smallest tag index instead of every report
+        let mut candidates: Option<Vec<u64>> = None;
+        for tag in filter.tags.iter() {
+            let ids = Self::index_get(&env, &(TAG_INDEX, tag));
+            if candidates.as_ref().is_none_or(|best| ids.len() < best.len()) {
+                candidates = Some(ids);
+            }
+        }
+        let total_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
+        
+        let mut page = ReportPage {
+            reports: Vec::new(&env),
+            next_cursor: None,
+        };
+        // Index lists are kept in ascending ID order, so both sources resume after the cursor
+        let mut next = match &candidates {
+            Some(ids) => match ids.binary_search(cursor + 1) {
+                Ok(pos) | Err(pos) => pos as u64,
+            },
+            None => cursor + 1,
+        };
+        let mut scanned = 0;
+        loop {
+            let report_id = match &candidates {
+                Some(ids) if next < ids.len() as u64 => ids.get(next as u32).unwrap(),
+                None if next <= total_count => next,
+                _ => break,
+            };
+            if scanned == SEARCH_SCAN_LIMIT || page.reports.len() == limit {
+                page.next_cursor = Some(report_id - 1);
+                break;
+            }
+            scanned += 1;
+            next += 1;
+            
+            if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+                if Self::matches_filter(&report, &filter) {
+                    page.reports.push_back(report);
+                }
+            }
+        }
+        
+        page
+    }
+
+    /// Set a report's urgency level (verifier only)
+    pub fn set_urgency(env: Env, report_id: u64, verifier: Address, urgency: String, reason: String) -> bool {
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can set urgency");
+        }
+        Self::urgency_weight(&env, &urgency);
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if Self::is_terminal_status(&env, &report.status) {
+            panic!("Report is already closed");
+        }
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            verifier,
+            String::from_str(&env, "urgency"),
+            report.urgency.clone(),
+            urgency.clone(),
+            reason,
+        );
+        
+        report.urgency = urgency;
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        true
+    }
+
+    /// Set or clear the date by which a report's need must be met (creator only)
+    pub fn set_deadline(env: Env, report_id: u64, creator: Address, deadline: Option<u64>) -> bool {
+        creator.require_auth();
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.creator != creator {
+            panic!("Unauthorized: Only creator can set the deadline");
+        }
+        if deadline.is_some_and(|deadline| deadline <= env.ledger().timestamp()) {
+            panic!("Deadline must be in the future");
+        }
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            creator,
+            String::from_str(&env, "deadline"),
+            String::from_str(&env, "deadline_updated"),
+            String::from_str(&env, "new_deadline"),
+            String::from_str(&env, "Deadline updated"),
+        );
+        
+        report.deadline = deadline;
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        true
+    }
+
+    /// Rank reports still raising funds by the given key, starting at position `cursor`
+    pub fn get_reports_sorted(env: Env, by: SortKey, cursor: u64, limit: u32) -> ReportPage {
+        let directory = Self::sorted_directory(&env, &by);
+        let total: u64 = directory.pages.iter().map(|sorted_page| sorted_page.len as u64).sum();
+        
+        let mut page = ReportPage {
+            reports: Vec::new(&env),
+            next_cursor: None,
+        };
+        let end = cursor.saturating_add(limit as u64).min(total);
+        
+        // Only the index pages overlapping [cursor, end) are loaded
+        let mut page_start: u64 = 0;
+        for sorted_page in directory.pages.iter() {
+            if page_start >= end {
+                break;
+            }
+            let page_end = page_start + sorted_page.len as u64;
+            if page_end > cursor {
+                let entries = Self::sorted_page(&env, &by, sorted_page.page_id);
+                let from = (cursor.max(page_start) - page_start) as u32;
+                let to = (end.min(page_end) - page_start) as u32;
+                for (_, report_id) in entries.slice(from..to).iter() {
+                    if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+                        page.reports.push_back(report);
+                    }
+                }
+            }
+            page_start = page_end;
+        }
+        if end < total {
+            page.next_cursor = Some(end);
+        }
+        
+        page
+    }
+
+    /// Get reports whose geohash starts with the given prefix
+    pub fn get_reports_near(env: Env, geohash_prefix: String) -> Vec<NeedReport> {
+        Self::validate_geohash(&geohash_prefix);
+        
+        // Longer prefixes are served from the deepest index and filtered
+        let indexed_len = geohash_prefix.len().min(GEOHASH_INDEX_PRECISION);
+        let indexed_prefix = Self::geohash_prefix(&env, &geohash_prefix, indexed_len);
+        let ids = Self::index_get(&env, &(GEOHASH_INDEX, indexed_prefix));
+        
+        let mut reports = Vec::new(&env);
+        for id in ids.iter() {
+            if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&id) {
+                if geohash_prefix.len() <= GEOHASH_INDEX_PRECISION {
+                    reports.push_back(report);
+                } else if let Some(geohash) = report.geo.geohash.clone() {
+                    if geohash.len() >= geohash_prefix.len()
+                        && Self::geohash_prefix(&env, &geohash, geohash_prefix.len()) == geohash_prefix
+                    {
+                        reports.push_back(report);
+                    }
+                }
+            }
+        }
+        
+        reports
+    }
+
     /// Get change log for a specific report
     pub fn get_change_log(env: Env, report_id: u64) -> Vec<ChangeLog> {
         // Use a different storage namespace for logs
@@ -425,8 +1418,21 @@ impl NeedReportsContract {
             completed_reports: 0,
             total_amount_needed: 0,
             total_amount_raised: 0,
+            total_fees_collected: env.storage().instance().get::<Symbol, i128>(&TOTAL_FEES).unwrap_or(0),
+            total_tips_collected: env.storage().instance().get::<Symbol, i128>(&TOTAL_TIPS).unwrap_or(0),
+            category_stats: Vec::new(&env),
         };
         
+        // Seed per-category totals in registry order
+        for category in Self::list_categories(env.clone()).iter() {
+            stats.category_stats.push_back(CategoryStats {
+                category: category.id,
+                report_count: 0,
+                amount_needed: 0,
+                amount_raised: 0,
+            });
+        }
+        
         let pending_status = String::from_str(&env, "pending");
         let verified_status = String::from_str(&env, "verified");
         let funded_status = String::from_str(&env, "funded");
@@ -434,8 +1440,9 @@ impl NeedReportsContract {
         
         for i in 1..=total_count {
             if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&i) {
-                stats.total_amount_needed += report.amount_needed;
-                stats.total_amount_raised += report.amount_raised;
+                stats.total_amount_needed = amount::add(&env, stats.total_amount_needed, report.amount_needed);
+                stats.total_amount_raised = amount::add(&env, stats.total_amount_raised, report.amount_raised);
+                Self::add_to_category_stats(&env, &mut stats.category_stats, &report.category, report.amount_needed, report.amount_raised);
                 
                 if report.status == pending_status {
                     stats.pending_reports += 1;
@@ -446,49 +1453,2987 @@ impl NeedReportsContract {
                 } else if report.status == completed_status {
                     stats.completed_reports += 1;
                 }
+            } else if let Some(archived) = Self::get_archived_report(env.clone(), i) {
+                // Archived reports are closed, so they only count towards totals and completions
+                stats.total_amount_needed = amount::add(&env, stats.total_amount_needed, archived.amount_needed);
+                stats.total_amount_raised = amount::add(&env, stats.total_amount_raised, archived.amount_raised);
+                Self::add_to_category_stats(&env, &mut stats.category_stats, &archived.category, archived.amount_needed, archived.amount_raised);
+                
+                if archived.final_status == completed_status {
+                    stats.completed_reports += 1;
+                }
             }
         }
         
         stats
     }
 
-    /// Check if an address is an admin
-    pub fn is_admin(env: Env, address: Address) -> bool {
-        if let Some(admins) = env.storage().instance().get::<Symbol, Vec<Address>>(&ADMIN_LIST) {
-            for admin in admins.iter() {
-                if admin == address {
-                    return true;
-                }
-            }
+    /// Set the TTL policy for a class of stored data (admin only)
+    pub fn set_ttl_policy(env: Env, admin: Address, class: DataClass, threshold: u32, extend_to: u32) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can set TTL policies");
         }
-        false
+        
+        ttl::set_policy(&env, class, &TtlPolicy { threshold, extend_to });
+        true
     }
 
-    /// Add admin (existing admin only)
-    pub fn add_admin(env: Env, current_admin: Address, new_admin: Address) -> bool {
-        current_admin.require_auth();
+    /// Get the TTL policy for a class of stored data
+    pub fn get_ttl_policy(env: Env, class: DataClass) -> TtlPolicy {
+        ttl::get_policy(&env, class)
+    }
+
+    /// Extend the TTL of a report and its related records (permissionless, for keepers)
+    pub fn bump_report(env: Env, report_id: u64) -> bool {
+        if !env.storage().persistent().has(&report_id) {
+            return false;
+        }
         
-        if !Self::is_admin(env.clone(), current_admin) {
-            panic!("Unauthorized: Only admin can add new admin");
+        ttl::extend_instance(&env);
+        ttl::extend_report(&env, report_id);
+        ttl::extend_if_present(&env, &(Symbol::new(&env, "LOG"), report_id), DataClass::ChangeLog);
+        ttl::extend_if_present(&env, &(DONATIONS, report_id), DataClass::Funds);
+        ttl::extend_if_present(&env, &(ESCROW, report_id), DataClass::Funds);
+        for key in [AMENDMENTS, DISPUTES, COMPLETIONS, ATTESTERS, OVERFUNDING, QF_REPORT_ROUNDS, PRIVATE_DETAILS] {
+            ttl::extend_if_present(&env, &(key, report_id), DataClass::Record);
+        }
+        true
+    }
+
+    /// Bump every report in an ID range, at most MAX_BUMP_RANGE at a time; returns how many existed
+    pub fn bump_range(env: Env, start_id: u64, end_id: u64) -> u32 {
+        if end_id < start_id || end_id - start_id >= MAX_BUMP_RANGE {
+            panic!("Invalid bump range");
         }
         
-        if let Some(mut admins) = env.storage().instance().get::<Symbol, Vec<Address>>(&ADMIN_LIST) {
-            // Check if already admin
-            for admin in admins.iter() {
-                if admin == new_admin {
-                    return false; // Already an admin
-                }
+        let mut bumped = 0;
+        for report_id in start_id..=end_id {
+            if Self::bump_report(env.clone(), report_id) {
+                bumped += 1;
             }
-            
-            admins.push_back(new_admin);
-            env.storage().instance().set(&ADMIN_LIST, &admins);
-            env.storage().instance().extend_ttl(5184000, 5184000);
-            true
-        } else {
+        }
+        bumped
+    }
+
+    /// List reports whose estimated expiry falls within the given number of ledgers
+    pub fn get_expiring_reports(env: Env, within_ledgers: u32, offset: u64, limit: u64) -> Vec<ReportExpiry> {
+        let mut expiring = Vec::new(&env);
+        let total_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
+        let horizon = env.ledger().sequence().saturating_add(within_ledgers);
+        
+        let start = offset.max(1);
+        let end = start.saturating_add(limit).saturating_sub(1).min(total_count);
+        for report_id in start..=end {
+            let Some(live_until_ledger) = ttl::report_live_until(&env, report_id) else {
+                continue;
+            };
+            if live_until_ledger <= horizon {
+                expiring.push_back(ReportExpiry { report_id, live_until_ledger });
+            }
+        }
+        expiring
+    }
+
+    /// Replace a closed report with a compact summary and drop its full state (admin only)
+    pub fn archive_report(env: Env, report_id: u64, admin: Address) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can archive reports");
+        }
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if !Self::is_terminal_status(&env, &report.status) {
+            panic!("Only closed reports can be archived");
+        }
+        if Self::get_escrow_balance(env.clone(), report_id) != 0 {
+            panic!("Report still holds escrowed funds");
+        }
+        
+        let logs = Self::get_change_log(env.clone(), report_id);
+        let archived = ArchivedReport {
+            id: report_id,
+            creator: report.creator,
+            beneficiary: report.beneficiary,
+            category: report.category,
+            amount_needed: report.amount_needed,
+            amount_raised: report.amount_raised,
+            amount_released: report.amount_released,
+            decimals: report.decimals,
+            final_status: report.status,
+            created_at: report.created_at,
+            archived_at: env.ledger().timestamp(),
+            log_entries: logs.len(),
+            log_head_hash: env.crypto().sha256(&logs.to_xdr(&env)).into(),
+        };
+        let archive_key = (ARCHIVED, report_id);
+        env.storage().persistent().set(&archive_key, &archived);
+        ttl::extend(&env, &archive_key, DataClass::Record);
+        
+        // Drop the report from active indexes and remove its per-report state
+        Self::unindex_geo_location(&env, report_id, &report.geo);
+        for tag in report.tags.iter() {
+            Self::index_remove(&env, &(TAG_INDEX, tag), report_id);
+        }
+        let persistent = env.storage().persistent();
+        persistent.remove(&report_id);
+        persistent.remove(&(Symbol::new(&env, "LOG"), report_id));
+        for key in [DONATIONS, ESCROW, AMENDMENTS, DISPUTES, COMPLETIONS, ATTESTERS, OVERFUNDING, QF_REPORT_ROUNDS, PRIVATE_DETAILS] {
+            persistent.remove(&(key, report_id));
+        }
+        ttl::forget_report(&env, report_id);
+        
+        true
+    }
+
+    /// Get the summary of an archived report
+    pub fn get_archived_report(env: Env, report_id: u64) -> Option<ArchivedReport> {
+        env.storage().persistent().get::<(Symbol, u64), ArchivedReport>(&(ARCHIVED, report_id))
+    }
+
+    /// Pause the given scopes, e.g. `PAUSE_ALL` (admin only)
+    pub fn pause(env: Env, admin: Address, scope: u32) -> u32 {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can pause the contract");
+        }
+        if scope == 0 || scope & !PAUSE_ALL != 0 {
+            panic!("Invalid pause scope");
+        }
+        
+        let paused = Self::get_paused(env.clone()) | scope;
+        env.storage().instance().set(&PAUSED, &paused);
+        ttl::extend_instance(&env);
+        paused
+    }
+
+    /// Lift the given paused scopes (admin only)
+    pub fn unpause(env: Env, admin: Address, scope: u32) -> u32 {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can unpause the contract");
+        }
+        if scope == 0 || scope & !PAUSE_ALL != 0 {
+            panic!("Invalid pause scope");
+        }
+        
+        let paused = Self::get_paused(env.clone()) & !scope;
+        env.storage().instance().set(&PAUSED, &paused);
+        ttl::extend_instance(&env);
+        paused
+    }
+
+    /// Get the currently paused scopes as a bitmask of PAUSE_* flags
+    pub fn get_paused(env: Env) -> u32 {
+        env.storage().instance().get::<Symbol, u32>(&PAUSED).unwrap_or(0)
+    }
+
+    /// Check if an address is an admin
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        if let Some(admins) = env.storage().instance().get::<Symbol, Vec<Address>>(&ADMIN_LIST) {
+            for admin in admins.iter() {
+                if admin == address {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Record the identity level of a beneficiary (verifier only)
+    pub fn set_identity_level(
+        env: Env,
+        verifier: Address,
+        beneficiary: Address,
+        level: String,
+        evidence_hash: BytesN<32>,
+    ) -> bool {
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can set identity levels");
+        }
+        Self::require_identity_level(&env, &level);
+        
+        let identity = BeneficiaryIdentity {
+            address: beneficiary.clone(),
+            level,
+            evidence_hash,
+            verified_by: verifier,
+            updated_at: env.ledger().timestamp(),
+        };
+        
+        let key = (IDENTITIES, beneficiary);
+        env.storage().persistent().set(&key, &identity);
+        ttl::extend(&env, &key, DataClass::Record);
+        true
+    }
+
+    /// Get the recorded identity of a beneficiary
+    pub fn get_identity(env: Env, beneficiary: Address) -> Option<BeneficiaryIdentity> {
+        env.storage().persistent().get::<(Symbol, Address), BeneficiaryIdentity>(&(IDENTITIES, beneficiary))
+    }
+
+    /// Get the identity level of a beneficiary ("unverified" if never recorded)
+    pub fn get_identity_level(env: Env, beneficiary: Address) -> String {
+        match Self::get_identity(env.clone(), beneficiary) {
+            Some(identity) => identity.level,
+            None => String::from_str(&env, "unverified"),
+        }
+    }
+
+    /// Configure report limits for an identity level (admin only)
+    pub fn set_level_limits(
+        env: Env,
+        admin: Address,
+        level: String,
+        max_amount_needed: i128,
+        max_open_reports: u32,
+    ) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can set level limits");
+        }
+        Self::require_identity_level(&env, &level);
+        amount::non_negative(&env, max_amount_needed);
+        
+        let new_limits = LevelLimits {
+            level: level.clone(),
+            max_amount_needed,
+            max_open_reports,
+        };
+        
+        let mut all_limits = Self::get_level_limits(env.clone());
+        match all_limits.iter().position(|limits| limits.level == level) {
+            Some(pos) => all_limits.set(pos as u32, new_limits),
+            None => all_limits.push_back(new_limits),
+        }
+        
+        env.storage().instance().set(&LEVEL_LIMITS, &all_limits);
+        ttl::extend_instance(&env);
+        true
+    }
+
+    /// Get configured report limits; levels without an entry are unlimited
+    pub fn get_level_limits(env: Env) -> Vec<LevelLimits> {
+        env.storage().instance()
+            .get::<Symbol, Vec<LevelLimits>>(&LEVEL_LIMITS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the number of non-terminal reports open for a beneficiary
+    pub fn get_open_report_count(env: Env, beneficiary: Address) -> u32 {
+        env.storage().persistent()
+            .get::<(Symbol, Address), u32>(&(OPEN_REPORTS, beneficiary))
+            .unwrap_or(0)
+    }
+
+    /// Authorize a delegate for one report or, with `report_id` unset, all of the grantor's reports
+    pub fn add_delegate(
+        env: Env,
+        grantor: Address,
+        report_id: Option<u64>,
+        delegate: Address,
+        permissions: u32,
+        expires_at: u64,
+    ) -> bool {
+        grantor.require_auth();
+        
+        if permissions == 0 || permissions & !(DELEGATE_UPDATE_REPORT | DELEGATE_PROPOSE_AMENDMENT) != 0 {
+            panic!("Invalid delegate permissions");
+        }
+        if expires_at <= env.ledger().timestamp() {
+            panic!("Delegation expiry must be in the future");
+        }
+        if let Some(id) = report_id {
+            Self::require_report_creator(&env, id, &grantor);
+        }
+        
+        // Re-granting replaces the previous permissions and expiry
+        let mut delegations = Self::get_delegations(env.clone(), grantor.clone());
+        if let Some(pos) = delegations.iter().position(|d| d.delegate == delegate && d.report_id == report_id) {
+            delegations.remove(pos as u32);
+        }
+        delegations.push_back(Delegation {
+            grantor: grantor.clone(),
+            delegate: delegate.clone(),
+            report_id,
+            permissions,
+            expires_at,
+            created_at: env.ledger().timestamp(),
+        });
+        Self::save_delegations(&env, &grantor, &delegations);
+        
+        Self::log_delegation_change(
+            &env,
+            &grantor,
+            report_id,
+            String::from_str(&env, ""),
+            delegate.to_string(),
+            String::from_str(&env, "Delegate authorized"),
+        );
+        true
+    }
+
+    /// Revoke a delegation previously granted with `add_delegate`
+    pub fn remove_delegate(env: Env, grantor: Address, report_id: Option<u64>, delegate: Address) -> bool {
+        grantor.require_auth();
+        
+        let mut delegations = Self::get_delegations(env.clone(), grantor.clone());
+        let Some(pos) = delegations.iter().position(|d| d.delegate == delegate && d.report_id == report_id) else {
+            return false;
+        };
+        delegations.remove(pos as u32);
+        Self::save_delegations(&env, &grantor, &delegations);
+        
+        Self::log_delegation_change(
+            &env,
+            &grantor,
+            report_id,
+            delegate.to_string(),
+            String::from_str(&env, ""),
+            String::from_str(&env, "Delegate revoked"),
+        );
+        true
+    }
+
+    /// Get all delegations granted by an address, including expired ones
+    pub fn get_delegations(env: Env, grantor: Address) -> Vec<Delegation> {
+        env.storage().persistent()
+            .get::<(Symbol, Address), Vec<Delegation>>(&(DELEGATIONS, grantor))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Check if an address holds an unexpired delegation with the given permission for a report
+    pub fn is_delegate(env: Env, report_id: u64, delegate: Address, permission: u32) -> bool {
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        
+        let now = env.ledger().timestamp();
+        Self::get_delegations(env, report.creator).iter().any(|d| {
+            d.delegate == delegate
+                && (d.report_id.is_none() || d.report_id == Some(report_id))
+                && d.permissions & permission == permission
+                && d.expires_at > now
+        })
+    }
+
+    /// Register an organization and its first org admin (platform admin only)
+    pub fn register_organization(
+        env: Env,
+        admin: Address,
+        name: String,
+        org_admin: Address,
+        payout_address: Address,
+    ) -> u64 {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can register organizations");
+        }
+        
+        let org_id = env.storage().instance().get::<Symbol, u64>(&NEXT_ORG_ID).unwrap_or(1);
+        let mut org_admins = Vec::new(&env);
+        org_admins.push_back(org_admin);
+        
+        let organization = Organization {
+            id: org_id,
+            name,
+            admins: org_admins,
+            members: Vec::new(&env),
+            payout_address,
+            payout_to_beneficiary: false,
+            created_at: env.ledger().timestamp(),
+        };
+        
+        Self::save_organization(&env, &organization);
+        env.storage().instance().set(&NEXT_ORG_ID, &(org_id + 1));
+        ttl::extend_instance(&env);
+        
+        org_id
+    }
+
+    /// Get an organization by ID
+    pub fn get_organization(env: Env, organization_id: u64) -> Option<Organization> {
+        env.storage().persistent().get::<(Symbol, u64), Organization>(&(ORGANIZATIONS, organization_id))
+    }
+
+    /// Check if an address can act for an organization (members and org admins)
+    pub fn is_org_member(env: Env, organization_id: u64, address: Address) -> bool {
+        match Self::get_organization(env, organization_id) {
+            Some(organization) => organization.members.contains(&address) || organization.admins.contains(&address),
+            None => false,
+        }
+    }
+
+    /// Add a member to an organization (org admin only)
+    pub fn add_org_member(env: Env, organization_id: u64, org_admin: Address, member: Address) -> bool {
+        let mut organization = Self::require_org_admin(&env, organization_id, &org_admin);
+        
+        if organization.members.contains(&member) {
+            return false; // Already a member
+        }
+        
+        organization.members.push_back(member);
+        Self::save_organization(&env, &organization);
+        true
+    }
+
+    /// Remove a member from an organization (org admin only)
+    pub fn remove_org_member(env: Env, organization_id: u64, org_admin: Address, member: Address) -> bool {
+        let mut organization = Self::require_org_admin(&env, organization_id, &org_admin);
+        
+        if let Some(pos) = organization.members.first_index_of(&member) {
+            organization.members.remove(pos);
+            Self::save_organization(&env, &organization);
+            true
+        } else {
             false
         }
     }
 
+    /// Add another admin to an organization (org admin only)
+    pub fn add_org_admin(env: Env, organization_id: u64, org_admin: Address, new_admin: Address) -> bool {
+        let mut organization = Self::require_org_admin(&env, organization_id, &org_admin);
+        
+        if organization.admins.contains(&new_admin) {
+            return false; // Already an org admin
+        }
+        
+        organization.admins.push_back(new_admin);
+        Self::save_organization(&env, &organization);
+        true
+    }
+
+    /// Configure where payouts for the organization's reports are sent (org admin only)
+    pub fn set_org_payout(
+        env: Env,
+        organization_id: u64,
+        org_admin: Address,
+        payout_address: Address,
+        payout_to_beneficiary: bool,
+    ) -> bool {
+        let mut organization = Self::require_org_admin(&env, organization_id, &org_admin);
+        
+        organization.payout_address = payout_address;
+        organization.payout_to_beneficiary = payout_to_beneficiary;
+        Self::save_organization(&env, &organization);
+        true
+    }
+
+    /// Set the token accepted for donations, once (admin only)
+    pub fn set_donation_token(env: Env, admin: Address, token: Address) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can set the donation token");
+        }
+        // Escrow, pool balances and each report's decimals are all denominated in the first token
+        if env.storage().instance().has(&DONATION_TOKEN) {
+            panic!("Donation token is already set");
+        }
+        
+        env.storage().instance().set(&DONATION_TOKEN, &token);
+        ttl::extend_instance(&env);
+        true
+    }
+
+    /// Get the token accepted for donations
+    pub fn get_donation_token(env: Env) -> Option<Address> {
+        env.storage().instance().get::<Symbol, Address>(&DONATION_TOKEN)
+    }
+
+    /// Donate to a verified report; the platform fee is deducted and an optional tip goes to the treasury
+    pub fn donate(env: Env, report_id: u64, donor: Address, amount: i128, tip: i128) -> bool {
+        Self::require_not_paused(&env, PAUSE_DONATIONS);
+        
+        donor.require_auth();
+        
+        amount::non_negative(&env, tip);
+        if amount::non_negative(&env, amount) == 0 {
+            panic!("Donation amount must be positive");
+        }
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        Self::require_accepting_donations(&env, &report);
+        
+        Self::collect_donation(&env, report, &donor, amount, tip, false, false);
+        true
+    }
+
+    /// Set how donations beyond a report's remaining need are handled (creator or admin)
+    pub fn set_overfunding_policy(env: Env, report_id: u64, caller: Address, policy: OverfundingPolicy) -> bool {
+        caller.require_auth();
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.creator != caller && !Self::is_admin(env.clone(), caller.clone()) {
+            panic!("Unauthorized: Only creator or admin can set the overfunding policy");
+        }
+        
+        let key = (OVERFUNDING, report_id);
+        env.storage().persistent().set(&key, &policy);
+        ttl::extend(&env, &key, DataClass::Record);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            caller,
+            String::from_str(&env, "overfunding_policy"),
+            String::from_str(&env, ""),
+            String::from_str(&env, Self::overfunding_policy_name(&policy)),
+            String::from_str(&env, "Overfunding policy updated"),
+        );
+        true
+    }
+
+    /// Get a report's overfunding policy
+    pub fn get_overfunding_policy(env: Env, report_id: u64) -> OverfundingPolicy {
+        env.storage().persistent()
+            .get::<(Symbol, u64), OverfundingPolicy>(&(OVERFUNDING, report_id))
+            .unwrap_or(OverfundingPolicy::Accept)
+    }
+
+    /// Get the surplus held in the general fund
+    pub fn get_general_fund(env: Env) -> i128 {
+        env.storage().instance().get::<Symbol, i128>(&GENERAL_FUND).unwrap_or(0)
+    }
+
+    /// Move general fund surplus into a report's escrow (admin only)
+    pub fn allocate_general_fund(env: Env, admin: Address, report_id: u64, amount: i128) -> bool {
+        Self::require_not_paused(&env, PAUSE_DONATIONS);
+        
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin.clone()) {
+            panic!("Unauthorized: Only admin can allocate the general fund");
+        }
+        let balance = Self::get_general_fund(env.clone());
+        if amount::non_negative(&env, amount) == 0 || amount > balance {
+            panic!("Invalid general fund amount");
+        }
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        Self::require_accepting_donations(&env, &report);
+        
+        env.storage().instance().set(&GENERAL_FUND, &(balance - amount));
+        ttl::extend_instance(&env);
+        
+        // Booked as the contract's own donation so refunds flow back into the general fund
+        Self::credit_donation(&env, report, env.current_contract_address(), amount, 0, 0);
+        true
+    }
+
+    /// Create a campaign that related reports can join (verifier only)
+    pub fn create_campaign(
+        env: Env,
+        organizer: Address,
+        title: String,
+        starts_at: u64,
+        ends_at: u64,
+        country_code: String,
+        region: String,
+        category: String,
+        split_rule: SplitRule,
+    ) -> u64 {
+        organizer.require_auth();
+        
+        if !Self::is_verifier(env.clone(), organizer.clone()) {
+            panic!("Unauthorized: Only verifier can create campaigns");
+        }
+        if ends_at <= starts_at || ends_at <= env.ledger().timestamp() {
+            panic!("Invalid campaign time window");
+        }
+        Self::require_active_category(&env, &category);
+        
+        let campaign_id = env.storage().instance().get::<Symbol, u64>(&NEXT_CAMPAIGN_ID).unwrap_or(1);
+        let campaign = Campaign {
+            id: campaign_id,
+            title,
+            organizer,
+            starts_at,
+            ends_at,
+            country_code,
+            region,
+            category,
+            split_rule,
+            report_ids: Vec::new(&env),
+            total_donated: 0,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_campaign(&env, &campaign);
+        env.storage().instance().set(&NEXT_CAMPAIGN_ID, &(campaign_id + 1));
+        ttl::extend_instance(&env);
+        
+        campaign_id
+    }
+
+    /// Add a report to a campaign (report creator or campaign organizer)
+    pub fn join_campaign(env: Env, campaign_id: u64, caller: Address, report_id: u64) -> bool {
+        caller.require_auth();
+        
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id)
+            .unwrap_or_else(|| panic!("Campaign not found"));
+        let mut report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        
+        if report.creator != caller && campaign.organizer != caller {
+            panic!("Unauthorized: Only creator or campaign organizer can add a report");
+        }
+        if report.campaign_id.is_some() {
+            return false; // Already in a campaign
+        }
+        if report.category != campaign.category
+            || report.geo.country_code != campaign.country_code
+            || report.geo.region != campaign.region
+        {
+            panic!("Report does not match the campaign category and region");
+        }
+        if Self::is_terminal_status(&env, &report.status) {
+            panic!("Closed reports cannot join a campaign");
+        }
+        
+        campaign.report_ids.push_back(report_id);
+        Self::save_campaign(&env, &campaign);
+        
+        report.campaign_id = Some(campaign_id);
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            caller,
+            String::from_str(&env, "campaign"),
+            String::from_str(&env, ""),
+            campaign.title,
+            String::from_str(&env, "Joined campaign"),
+        );
+        true
+    }
+
+    /// Donate to a campaign; the amount is split across member reports by the campaign's rule
+    pub fn donate_to_campaign(env: Env, campaign_id: u64, donor: Address, amount: i128, tip: i128) -> bool {
+        Self::require_not_paused(&env, PAUSE_DONATIONS);
+        
+        donor.require_auth();
+        
+        amount::non_negative(&env, tip);
+        if amount::non_negative(&env, amount) == 0 {
+            panic!("Donation amount must be positive");
+        }
+        
+        let mut campaign = Self::get_campaign(env.clone(), campaign_id)
+            .unwrap_or_else(|| panic!("Campaign not found"));
+        let now = env.ledger().timestamp();
+        if now < campaign.starts_at || now >= campaign.ends_at {
+            panic!("Campaign is not accepting donations");
+        }
+        
+        // Only members currently accepting donations take part in the split; members that
+        // reject overfunding drop out once fully funded
+        let verified_status = String::from_str(&env, "verified");
+        let funded_status = String::from_str(&env, "funded");
+        let mut recipients = Vec::new(&env);
+        for report_id in campaign.report_ids.iter() {
+            if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+                if report.status != verified_status && report.status != funded_status {
+                    continue;
+                }
+                if Self::remaining_need(&report) == 0
+                    && Self::get_overfunding_policy(env.clone(), report_id) == OverfundingPolicy::Reject
+                {
+                    continue;
+                }
+                recipients.push_back(report);
+            }
+        }
+        if recipients.is_empty() {
+            panic!("Campaign has no reports accepting donations");
+        }
+        
+        let shares = Self::split_amount(&env, &campaign.split_rule, &recipients, amount);
+        let mut tip_remaining = tip;
+        for (i, report) in recipients.iter().enumerate() {
+            let share = shares.get(i as u32).unwrap();
+            if share == 0 {
+                continue;
+            }
+            Self::collect_donation(&env, report, &donor, share, tip_remaining, false, true);
+            tip_remaining = 0;
+        }
+        
+        campaign.total_donated = amount::add(&env, campaign.total_donated, amount);
+        Self::save_campaign(&env, &campaign);
+        true
+    }
+
+    /// Get a campaign by ID
+    pub fn get_campaign(env: Env, campaign_id: u64) -> Option<Campaign> {
+        env.storage().persistent().get::<(Symbol, u64), Campaign>(&(CAMPAIGNS, campaign_id))
+    }
+
+    /// Get aggregate statistics for a campaign's member reports
+    pub fn get_campaign_stats(env: Env, campaign_id: u64) -> CampaignStats {
+        let campaign = Self::get_campaign(env.clone(), campaign_id)
+            .unwrap_or_else(|| panic!("Campaign not found"));
+        
+        let mut stats = CampaignStats {
+            campaign_id,
+            total_reports: campaign.report_ids.len() as u64,
+            funded_reports: 0,
+            completed_reports: 0,
+            total_amount_needed: 0,
+            total_amount_raised: 0,
+            total_donated: campaign.total_donated,
+        };
+        
+        let funded_status = String::from_str(&env, "funded");
+        let completed_status = String::from_str(&env, "completed");
+        for report_id in campaign.report_ids.iter() {
+            if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+                stats.total_amount_needed = amount::add(&env, stats.total_amount_needed, report.amount_needed);
+                stats.total_amount_raised = amount::add(&env, stats.total_amount_raised, report.amount_raised);
+                if report.status == funded_status {
+                    stats.funded_reports += 1;
+                } else if report.status == completed_status {
+                    stats.completed_reports += 1;
+                }
+            } else if let Some(archived) = Self::get_archived_report(env.clone(), report_id) {
+                stats.total_amount_needed = amount::add(&env, stats.total_amount_needed, archived.amount_needed);
+                stats.total_amount_raised = amount::add(&env, stats.total_amount_raised, archived.amount_raised);
+                if archived.final_status == completed_status {
+                    stats.completed_reports += 1;
+                }
+            }
+        }
+        
+        stats
+    }
+
+    /// Set up a recurring donation; the donor must `approve` this contract on the donation token
+    pub fn create_subscription(
+        env: Env,
+        donor: Address,
+        target: SubscriptionTarget,
+        amount: i128,
+        period_secs: u64,
+    ) -> u64 {
+        donor.require_auth();
+        
+        if amount::non_negative(&env, amount) == 0 || period_secs == 0 {
+            panic!("Subscription amount and period must be positive");
+        }
+        match &target {
+            SubscriptionTarget::Report(report_id) => {
+                if !env.storage().persistent().has(report_id) {
+                    panic!("Report not found");
+                }
+            }
+            SubscriptionTarget::Category(category) => Self::require_active_category(&env, category),
+            SubscriptionTarget::Beneficiary(_) => {}
+        }
+        
+        let subscription_id = env.storage().instance().get::<Symbol, u64>(&NEXT_SUBSCRIPTION_ID).unwrap_or(1);
+        let subscription = Subscription {
+            id: subscription_id,
+            donor: donor.clone(),
+            target,
+            amount,
+            period_secs,
+            next_due: env.ledger().timestamp(),
+            payments_made: 0,
+            active: true,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_subscription(&env, &subscription);
+        Self::index_add(&env, &(DONOR_SUBSCRIPTIONS, donor), subscription_id);
+        
+        env.storage().instance().set(&NEXT_SUBSCRIPTION_ID, &(subscription_id + 1));
+        ttl::extend_instance(&env);
+        
+        subscription_id
+    }
+
+    /// Stop a recurring donation (donor only)
+    pub fn cancel_subscription(env: Env, subscription_id: u64, donor: Address) -> bool {
+        donor.require_auth();
+        
+        let Some(mut subscription) = Self::get_subscription(env.clone(), subscription_id) else {
+            return false;
+        };
+        if subscription.donor != donor {
+            panic!("Unauthorized: Only donor can cancel the subscription");
+        }
+        if !subscription.active {
+            return false;
+        }
+        
+        subscription.active = false;
+        Self::save_subscription(&env, &subscription);
+        true
+    }
+
+    /// Collect a due subscription payment as a normal donation; returns the report paid (keeper call)
+    pub fn process_subscription(env: Env, subscription_id: u64) -> Option<u64> {
+        Self::require_not_paused(&env, PAUSE_DONATIONS);
+        
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id)
+            .unwrap_or_else(|| panic!("Subscription not found"));
+        if !subscription.active {
+            panic!("Subscription is not active");
+        }
+        if env.ledger().timestamp() < subscription.next_due {
+            panic!("Subscription payment is not due yet");
+        }
+        
+        let report = Self::subscription_report(&env, &subscription.target)?;
+        let report_id = report.id;
+        Self::collect_donation(&env, report, &subscription.donor, subscription.amount, 0, true, false);
+        
+        subscription.next_due += subscription.period_secs;
+        subscription.payments_made += 1;
+        Self::save_subscription(&env, &subscription);
+        
+        Some(report_id)
+    }
+
+    /// Get a subscription by ID
+    pub fn get_subscription(env: Env, subscription_id: u64) -> Option<Subscription> {
+        env.storage().persistent().get::<(Symbol, u64), Subscription>(&(SUBSCRIPTIONS, subscription_id))
+    }
+
+    /// Get all subscriptions created by a donor
+    pub fn get_donor_subscriptions(env: Env, donor: Address) -> Vec<Subscription> {
+        let mut subscriptions = Vec::new(&env);
+        for id in Self::index_get(&env, &(DONOR_SUBSCRIPTIONS, donor)).iter() {
+            if let Some(subscription) = Self::get_subscription(env.clone(), id) {
+                subscriptions.push_back(subscription);
+            }
+        }
+        subscriptions
+    }
+
+    /// Fund a matching pool for donations in a category and/or region (sponsor)
+    pub fn create_matching_pool(
+        env: Env,
+        sponsor: Address,
+        amount: i128,
+        ratio_bps: u32,
+        category: Option<String>,
+        country_code: Option<String>,
+        region: Option<String>,
+        cap_per_report: i128,
+        urgency_weighted: bool,
+        expires_at: u64,
+    ) -> u64 {
+        sponsor.require_auth();
+        
+        if amount::non_negative(&env, amount) == 0 || ratio_bps == 0 || amount::non_negative(&env, cap_per_report) == 0 {
+            panic!("Matching pool amount, ratio and cap must be positive");
+        }
+        if expires_at <= env.ledger().timestamp() {
+            panic!("Matching pool expiry must be in the future");
+        }
+        if region.is_some() && country_code.is_none() {
+            panic!("Region filter requires a country code");
+        }
+        if let Some(category) = &category {
+            Self::require_active_category(&env, category);
+        }
+        
+        // Matched funds join the report escrow, so pools use the donation token
+        let token = Self::get_donation_token(env.clone())
+            .unwrap_or_else(|| panic!("Donation token not configured"));
+        token::Client::new(&env, &token).transfer(&sponsor, &env.current_contract_address(), &amount);
+        
+        let pool_id = env.storage().instance().get::<Symbol, u64>(&NEXT_POOL_ID).unwrap_or(1);
+        let pool = MatchingPool {
+            id: pool_id,
+            sponsor,
+            token,
+            balance: amount,
+            ratio_bps,
+            category,
+            country_code,
+            region,
+            cap_per_report,
+            urgency_weighted,
+            expires_at,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_pool(&env, &pool);
+        
+        let mut active_pools = Self::get_active_pools(env.clone());
+        active_pools.push_back(pool_id);
+        env.storage().instance().set(&ACTIVE_POOLS, &active_pools);
+        env.storage().instance().set(&NEXT_POOL_ID, &(pool_id + 1));
+        ttl::extend_instance(&env);
+        
+        pool_id
+    }
+
+    /// Withdraw the unused balance of an expired matching pool (sponsor only)
+    pub fn withdraw_pool_balance(env: Env, pool_id: u64, sponsor: Address) -> i128 {
+        Self::require_not_paused(&env, PAUSE_PAYOUTS);
+        
+        sponsor.require_auth();
+        
+        let mut pool = Self::get_matching_pool(env.clone(), pool_id)
+            .unwrap_or_else(|| panic!("Matching pool not found"));
+        if pool.sponsor != sponsor {
+            panic!("Unauthorized: Only sponsor can withdraw the pool balance");
+        }
+        if env.ledger().timestamp() < pool.expires_at {
+            panic!("Matching pool has not expired yet");
+        }
+        
+        let withdrawn = pool.balance;
+        if withdrawn > 0 {
+            token::Client::new(&env, &pool.token).transfer(&env.current_contract_address(), &sponsor, &withdrawn);
+            pool.balance = 0;
+            Self::save_pool(&env, &pool);
+        }
+        
+        let mut active_pools = Self::get_active_pools(env.clone());
+        if let Some(pos) = active_pools.first_index_of(pool_id) {
+            active_pools.remove(pos);
+            env.storage().instance().set(&ACTIVE_POOLS, &active_pools);
+            ttl::extend_instance(&env);
+        }
+        
+        withdrawn
+    }
+
+    /// Create a quadratic funding round funded by the organizer (admin only)
+    pub fn create_qf_round(
+        env: Env,
+        organizer: Address,
+        title: String,
+        pool_amount: i128,
+        starts_at: u64,
+        ends_at: u64,
+    ) -> u64 {
+        organizer.require_auth();
+        
+        if !Self::is_admin(env.clone(), organizer.clone()) {
+            panic!("Unauthorized: Only admin can create funding rounds");
+        }
+        if amount::non_negative(&env, pool_amount) == 0 || ends_at <= starts_at || ends_at <= env.ledger().timestamp() {
+            panic!("Invalid funding round parameters");
+        }
+        
+        let token = Self::get_donation_token(env.clone())
+            .unwrap_or_else(|| panic!("Donation token not configured"));
+        token::Client::new(&env, &token).transfer(&organizer, &env.current_contract_address(), &pool_amount);
+        
+        let round_id = env.storage().instance().get::<Symbol, u64>(&NEXT_ROUND_ID).unwrap_or(1);
+        let round = QfRound {
+            id: round_id,
+            title,
+            organizer,
+            pool_amount,
+            starts_at,
+            ends_at,
+            report_ids: Vec::new(&env),
+            finalized: false,
+            created_at: env.ledger().timestamp(),
+        };
+        Self::save_round(&env, &round);
+        env.storage().instance().set(&NEXT_ROUND_ID, &(round_id + 1));
+        ttl::extend_instance(&env);
+        
+        round_id
+    }
+
+    /// Register an existing report as eligible for a round (creator or verifier, before the round ends)
+    pub fn register_for_round(env: Env, round_id: u64, caller: Address, report_id: u64) -> bool {
+        caller.require_auth();
+        
+        let mut round = Self::get_qf_round(env.clone(), round_id)
+            .unwrap_or_else(|| panic!("Funding round not found"));
+        let report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        
+        if report.creator != caller && !Self::is_verifier(env.clone(), caller) {
+            panic!("Unauthorized: Only creator or verifier can register a report");
+        }
+        if round.finalized || env.ledger().timestamp() >= round.ends_at {
+            panic!("Funding round is closed");
+        }
+        Self::require_accepting_donations(&env, &report);
+        
+        if round.report_ids.contains(report_id) {
+            return false; // Already registered
+        }
+        round.report_ids.push_back(report_id);
+        Self::save_round(&env, &round);
+        
+        let key = (QF_REPORT_ROUNDS, report_id);
+        let mut report_rounds = env.storage().persistent()
+            .get::<(Symbol, u64), Vec<u64>>(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        report_rounds.push_back(round_id);
+        env.storage().persistent().set(&key, &report_rounds);
+        ttl::extend(&env, &key, DataClass::Index);
+        
+        true
+    }
+
+    /// Distribute the round's pool by quadratic funding over unique donor contributions (admin only)
+    pub fn finalize_qf_round(env: Env, round_id: u64, admin: Address) -> Vec<QfAllocation> {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin.clone()) {
+            panic!("Unauthorized: Only admin can finalize funding rounds");
+        }
+        
+        let mut round = Self::get_qf_round(env.clone(), round_id)
+            .unwrap_or_else(|| panic!("Funding round not found"));
+        if round.finalized {
+            panic!("Funding round already finalized");
+        }
+        if env.ledger().timestamp() < round.ends_at {
+            panic!("Funding round has not ended yet");
+        }
+        
+        // Score each report; reports no longer accepting donations are skipped
+        let mut scores = Vec::new(&env);
+        let mut total_score: u128 = 0;
+        for report_id in round.report_ids.iter() {
+            let live = env.storage().persistent()
+                .get::<u64, NeedReport>(&report_id)
+                .map(|report| {
+                    report.status == String::from_str(&env, "verified")
+                        || report.status == String::from_str(&env, "funded")
+                })
+                .unwrap_or(false);
+            let score = if live {
+                qf::quadratic_score(&Self::get_round_contributions(env.clone(), round_id, report_id))
+            } else {
+                0
+            };
+            total_score += score;
+            scores.push_back(score);
+        }
+        
+        // Pro-rata shares round down; the remainder goes to the last scoring report
+        let last_scoring = scores.iter().rposition(|score| score > 0);
+        let mut distributed: i128 = 0;
+        let mut allocations = Vec::new(&env);
+        for (i, report_id) in round.report_ids.iter().enumerate() {
+            let score = scores.get(i as u32).unwrap();
+            let matched = if Some(i) == last_scoring {
+                amount::sub(&env, round.pool_amount, distributed)
+            } else {
+                qf::pro_rata(round.pool_amount, score, total_score)
+            };
+            distributed = amount::add(&env, distributed, matched);
+            
+            let contributions = Self::get_round_contributions(env.clone(), round_id, report_id);
+            let mut total_contributed: i128 = 0;
+            for (_, contributed) in contributions.iter() {
+                total_contributed = amount::add(&env, total_contributed, contributed);
+            }
+            allocations.push_back(QfAllocation {
+                report_id,
+                unique_donors: contributions.len(),
+                total_contributed,
+                matched,
+            });
+            
+            if matched > 0 {
+                let report = env.storage().persistent().get::<u64, NeedReport>(&report_id).unwrap();
+                Self::credit_donation(&env, report, round.organizer.clone(), matched, 0, 0);
+            }
+        }
+        
+        // With no scoring report the pool returns to the organizer
+        if distributed == 0 {
+            Self::require_not_paused(&env, PAUSE_PAYOUTS);
+            let token = Self::get_donation_token(env.clone())
+                .unwrap_or_else(|| panic!("Donation token not configured"));
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &round.organizer,
+                &round.pool_amount,
+            );
+        }
+        
+        round.finalized = true;
+        Self::save_round(&env, &round);
+        
+        let results_key = (QF_RESULTS, round_id);
+        env.storage().persistent().set(&results_key, &allocations);
+        ttl::extend(&env, &results_key, DataClass::Funds);
+        
+        allocations
+    }
+
+    /// Get a quadratic funding round by ID
+    pub fn get_qf_round(env: Env, round_id: u64) -> Option<QfRound> {
+        env.storage().persistent().get::<(Symbol, u64), QfRound>(&(QF_ROUNDS, round_id))
+    }
+
+    /// Get per-donor contribution totals to a report within a round
+    pub fn get_round_contributions(env: Env, round_id: u64, report_id: u64) -> Map<Address, i128> {
+        env.storage().persistent()
+            .get::<(Symbol, u64, u64), Map<Address, i128>>(&(QF_CONTRIBUTIONS, round_id, report_id))
+            .unwrap_or_else(|| Map::new(&env))
+    }
+
+    /// Get the allocations of a finalized round
+    pub fn get_qf_results(env: Env, round_id: u64) -> Vec<QfAllocation> {
+        env.storage().persistent()
+            .get::<(Symbol, u64), Vec<QfAllocation>>(&(QF_RESULTS, round_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get a matching pool by ID
+    pub fn get_matching_pool(env: Env, pool_id: u64) -> Option<MatchingPool> {
+        env.storage().persistent().get::<(Symbol, u64), MatchingPool>(&(POOLS, pool_id))
+    }
+
+    /// Get the IDs of pools that have not been withdrawn
+    pub fn get_active_pools(env: Env) -> Vec<u64> {
+        env.storage().instance()
+            .get::<Symbol, Vec<u64>>(&ACTIVE_POOLS)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get how much a pool has matched for a report
+    pub fn get_pool_matched(env: Env, pool_id: u64, report_id: u64) -> i128 {
+        env.storage().persistent()
+            .get::<(Symbol, u64, u64), i128>(&(POOL_MATCHED, pool_id, report_id))
+            .unwrap_or(0)
+    }
+
+    /// Configure the platform fee and treasury (admin only, fee capped at MAX_FEE_BPS)
+    pub fn set_fee_config(env: Env, admin: Address, fee_bps: u32, treasury: Address) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can set the fee config");
+        }
+        if fee_bps > MAX_FEE_BPS {
+            panic!("Fee exceeds the maximum allowed");
+        }
+        
+        env.storage().instance().set(&FEE_CONFIG, &FeeConfig { fee_bps, treasury });
+        ttl::extend_instance(&env);
+        true
+    }
+
+    /// Get the platform fee configuration
+    pub fn get_fee_config(env: Env) -> Option<FeeConfig> {
+        env.storage().instance().get::<Symbol, FeeConfig>(&FEE_CONFIG)
+    }
+
+    /// Release escrowed funds to the report's payout recipient (admin only)
+    pub fn release_funds(env: Env, report_id: u64, admin: Address, amount: i128) -> bool {
+        Self::require_not_paused(&env, PAUSE_PAYOUTS);
+        
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin.clone()) {
+            panic!("Unauthorized: Only admin can release funds");
+        }
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        
+        if report.status == String::from_str(&env, "disputed") {
+            panic!("Report is disputed: payouts are frozen");
+        }
+        // Only live reports pay out; completed reports have already accounted for their spending
+        if report.status != String::from_str(&env, "verified") && report.status != String::from_str(&env, "funded") {
+            panic!("Funds can only be released for verified or funded reports");
+        }
+        
+        if let Some(completion) = Self::get_completion(env.clone(), report_id) {
+            if completion.status == String::from_str(&env, "submitted") {
+                panic!("Funds cannot be released while a completion report is awaiting review");
+            }
+        }
+        
+        let escrow_balance = Self::get_escrow_balance(env.clone(), report_id);
+        if amount::non_negative(&env, amount) == 0 || amount > escrow_balance {
+            panic!("Release amount exceeds escrowed funds");
+        }
+        
+        let recipient = Self::get_payout_recipient(env.clone(), report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        let token = Self::get_donation_token(env.clone())
+            .unwrap_or_else(|| panic!("Donation token not configured"));
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &recipient, &amount);
+        
+        Self::set_escrow_balance(&env, report_id, amount::sub(&env, escrow_balance, amount));
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            admin,
+            String::from_str(&env, "amount_released"),
+            String::from_str(&env, "amount_updated"),
+            String::from_str(&env, "new_amount_released"),
+            String::from_str(&env, "Funds released"),
+        );
+        
+        report.amount_released = amount::add(&env, report.amount_released, amount);
+        report.updated_at = env.ledger().timestamp();
+        Self::save_report(&env, &report);
+        
+        true
+    }
+
+    /// Refund what is still escrowed for a rejected report to its donors (admin or donor)
+    pub fn refund_rejected(env: Env, report_id: u64, caller: Address) -> bool {
+        Self::require_not_paused(&env, PAUSE_PAYOUTS);
+        
+        caller.require_auth();
+        
+        let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        let is_donor = Self::get_donations(env.clone(), report_id)
+            .iter()
+            .any(|donation| donation.donor == caller);
+        if !is_donor && !Self::is_admin(env.clone(), caller.clone()) {
+            panic!("Unauthorized: Only admin or a donor can refund a rejected report");
+        }
+        if report.status != String::from_str(&env, "rejected") {
+            panic!("Only rejected reports can be refunded");
+        }
+        if Self::get_escrow_balance(env.clone(), report_id) == 0 {
+            return false;
+        }
+        
+        Self::refund_escrow(&env, report_id, &caller);
+        true
+    }
+
+    /// Submit proof of delivery and how released funds were spent, once escrow is fully released (creator only)
+    pub fn submit_completion(
+        env: Env,
+        report_id: u64,
+        creator: Address,
+        evidence: Vec<String>,
+        spend_breakdown: Vec<SpendItem>,
+    ) -> bool {
+        creator.require_auth();
+        
+        let report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        if report.creator != creator {
+            panic!("Unauthorized: Only creator can submit completion");
+        }
+        if report.amount_released == 0 {
+            panic!("No funds have been released for this report");
+        }
+        
+        if let Some(existing) = Self::get_completion(env.clone(), report_id) {
+            if existing.status == String::from_str(&env, "submitted") {
+                panic!("A completion report is already awaiting review");
+            }
+        }
+        
+        Self::require_completion_balanced(&env, &report, &spend_breakdown);
+        
+        let completion = CompletionReport {
+            report_id,
+            submitted_by: creator.clone(),
+            evidence,
+            spend_breakdown,
+            status: String::from_str(&env, "submitted"),
+            submitted_at: env.ledger().timestamp(),
+            reviewed_by: None,
+            reviewed_at: 0,
+            review_notes: String::from_str(&env, ""),
+        };
+        Self::save_completion(&env, &completion);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            creator,
+            String::from_str(&env, "completion"),
+            String::from_str(&env, ""),
+            String::from_str(&env, "submitted"),
+            String::from_str(&env, "Completion report submitted"),
+        );
+        true
+    }
+
+    /// Confirm the submitted completion report and mark the report completed (verifier only)
+    pub fn confirm_completion(env: Env, report_id: u64, verifier: Address, notes: String) -> bool {
+        let Some(mut completion) = Self::review_completion(&env, report_id, &verifier) else {
+            return false;
+        };
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        // The report may have been disputed or received more funds since the completion was submitted
+        Self::require_completion_balanced(&env, &report, &completion.spend_breakdown);
+        
+        completion.status = String::from_str(&env, "confirmed");
+        completion.reviewed_by = Some(verifier.clone());
+        completion.reviewed_at = env.ledger().timestamp();
+        completion.review_notes = notes.clone();
+        Self::save_completion(&env, &completion);
+        
+        Self::transition_status(&env, &mut report, &verifier, String::from_str(&env, "completed"), notes.clone());
+        report.verification_notes = notes;
+        Self::save_report(&env, &report);
+        true
+    }
+
+    /// Send the submitted completion report back to the creator (verifier only)
+    pub fn reject_completion(env: Env, report_id: u64, verifier: Address, notes: String) -> bool {
+        let Some(mut completion) = Self::review_completion(&env, report_id, &verifier) else {
+            return false;
+        };
+        
+        completion.status = String::from_str(&env, "rejected");
+        completion.reviewed_by = Some(verifier.clone());
+        completion.reviewed_at = env.ledger().timestamp();
+        completion.review_notes = notes.clone();
+        Self::save_completion(&env, &completion);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            verifier,
+            String::from_str(&env, "completion"),
+            String::from_str(&env, "submitted"),
+            String::from_str(&env, "rejected"),
+            notes,
+        );
+        true
+    }
+
+    /// Get the latest completion report for a report
+    pub fn get_completion(env: Env, report_id: u64) -> Option<CompletionReport> {
+        env.storage().persistent().get::<(Symbol, u64), CompletionReport>(&(COMPLETIONS, report_id))
+    }
+
+    /// Flag a report as suspicious, freezing donations and payouts until resolved
+    pub fn flag_report(
+        env: Env,
+        report_id: u64,
+        reporter: Address,
+        reason: String,
+        evidence_hash: BytesN<32>,
+    ) -> u32 {
+        reporter.require_auth();
+        
+        let mut report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        
+        // Only donors and verifiers have a stake in freezing a report
+        let is_donor = Self::get_donations(env.clone(), report_id)
+            .iter()
+            .any(|donation| donation.donor == reporter);
+        if !is_donor && !Self::is_verifier(env.clone(), reporter.clone()) {
+            panic!("Unauthorized: Only donors or verifiers can flag a report");
+        }
+        
+        let disputed_status = String::from_str(&env, "disputed");
+        if Self::is_terminal_status(&env, &report.status) {
+            panic!("Closed reports cannot be disputed");
+        }
+        
+        // Additional flags on a disputed report keep the status it had before the first one
+        let previous_status = if report.status == disputed_status {
+            Self::get_disputes(env.clone(), report_id)
+                .iter()
+                .find(|d| d.status == String::from_str(&env, "open"))
+                .map(|d| d.previous_status)
+                .unwrap_or_else(|| String::from_str(&env, "pending"))
+        } else {
+            report.status.clone()
+        };
+        
+        let mut disputes = Self::get_disputes(env.clone(), report_id);
+        let dispute_id = disputes.len();
+        disputes.push_back(Dispute {
+            id: dispute_id,
+            report_id,
+            reporter: reporter.clone(),
+            reason: reason.clone(),
+            evidence_hash,
+            status: String::from_str(&env, "open"),
+            previous_status,
+            created_at: env.ledger().timestamp(),
+            resolved_by: None,
+            resolved_at: 0,
+            resolution_notes: String::from_str(&env, ""),
+        });
+        Self::save_disputes(&env, report_id, &disputes);
+        
+        if report.status != disputed_status {
+            Self::transition_status(&env, &mut report, &reporter, disputed_status, reason);
+            Self::save_report(&env, &report);
+        }
+        
+        dispute_id
+    }
+
+    /// Resolve all open disputes on a report (admin only); upheld rejects it, dismissed restores it
+    pub fn resolve_dispute(
+        env: Env,
+        report_id: u64,
+        moderator: Address,
+        upheld: bool,
+        refund: bool,
+        notes: String,
+    ) -> bool {
+        if upheld && refund {
+            Self::require_not_paused(&env, PAUSE_PAYOUTS);
+        }
+        
+        moderator.require_auth();
+        
+        if !Self::is_admin(env.clone(), moderator.clone()) {
+            panic!("Unauthorized: Only admin can resolve disputes");
+        }
+        
+        let Some(mut report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+            return false;
+        };
+        if report.status != String::from_str(&env, "disputed") {
+            return false;
+        }
+        
+        let open_status = String::from_str(&env, "open");
+        let outcome = String::from_str(&env, if upheld { "upheld" } else { "dismissed" });
+        let mut disputes = Self::get_disputes(env.clone(), report_id);
+        let mut restored_status = String::from_str(&env, "pending");
+        for i in 0..disputes.len() {
+            let mut dispute = disputes.get(i).unwrap();
+            if dispute.status == open_status {
+                restored_status = dispute.previous_status.clone();
+                dispute.status = outcome.clone();
+                dispute.resolved_by = Some(moderator.clone());
+                dispute.resolved_at = env.ledger().timestamp();
+                dispute.resolution_notes = notes.clone();
+                disputes.set(i, dispute);
+            }
+        }
+        Self::save_disputes(&env, report_id, &disputes);
+        
+        if upheld {
+            reputation::record_upheld_dispute(&env, &report.beneficiary);
+            if let Some(attester) = Self::get_report_attester(env.clone(), report_id) {
+                reputation::record_overturned_attestation(&env, &attester);
+            }
+        }
+        
+        let new_status = if upheld { String::from_str(&env, "rejected") } else { restored_status };
+        Self::transition_status(&env, &mut report, &moderator, new_status, notes.clone());
+        report.verification_notes = notes;
+        Self::save_report(&env, &report);
+        
+        if upheld && refund {
+            Self::refund_escrow(&env, report_id, &moderator);
+        }
+        
+        true
+    }
+
+    /// Get all disputes raised against a report
+    pub fn get_disputes(env: Env, report_id: u64) -> Vec<Dispute> {
+        env.storage().persistent()
+            .get::<(Symbol, u64), Vec<Dispute>>(&(DISPUTES, report_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the address payouts for a report are routed to
+    pub fn get_payout_recipient(env: Env, report_id: u64) -> Option<Address> {
+        let report = env.storage().persistent().get::<u64, NeedReport>(&report_id)?;
+        
+        if let Some(organization_id) = report.organization_id {
+            if let Some(organization) = Self::get_organization(env, organization_id) {
+                if !organization.payout_to_beneficiary {
+                    return Some(organization.payout_address);
+                }
+            }
+        }
+        
+        Some(report.beneficiary)
+    }
+
+    /// Get all donations made to a report
+    pub fn get_donations(env: Env, report_id: u64) -> Vec<Donation> {
+        env.storage().persistent()
+            .get::<(Symbol, u64), Vec<Donation>>(&(DONATIONS, report_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the funds currently held in escrow for a report
+    pub fn get_escrow_balance(env: Env, report_id: u64) -> i128 {
+        env.storage().persistent()
+            .get::<(Symbol, u64), i128>(&(ESCROW, report_id))
+            .unwrap_or(0)
+    }
+
+    /// Get the reputation derived from an address's report and attestation history
+    pub fn get_reputation(env: Env, address: Address) -> Reputation {
+        reputation::get_reputation(&env, &address)
+    }
+
+    /// Get the verifier who attested to a report by moving it to "verified"
+    pub fn get_report_attester(env: Env, report_id: u64) -> Option<Address> {
+        env.storage().persistent().get::<(Symbol, u64), Address>(&(ATTESTERS, report_id))
+    }
+
+    /// Check if a report's core fields are frozen (verified or later, or under dispute)
+    pub fn is_report_locked(env: Env, report_id: u64) -> bool {
+        if let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) {
+            Self::is_locked_status(&env, &report.status)
+        } else {
+            false
+        }
+    }
+
+    /// Check if an address is a verifier (admins are implicitly verifiers)
+    pub fn is_verifier(env: Env, address: Address) -> bool {
+        if Self::is_admin(env.clone(), address.clone()) {
+            return true;
+        }
+        
+        if let Some(verifiers) = env.storage().instance().get::<Symbol, Vec<Address>>(&VERIFIER_LIST) {
+            return verifiers.contains(&address);
+        }
+        false
+    }
+
+    /// Add verifier (admin only)
+    pub fn add_verifier(env: Env, admin: Address, verifier: Address) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can add verifiers");
+        }
+        
+        let mut verifiers = env.storage().instance()
+            .get::<Symbol, Vec<Address>>(&VERIFIER_LIST)
+            .unwrap_or_else(|| Vec::new(&env));
+        
+        if verifiers.contains(&verifier) {
+            return false; // Already a verifier
+        }
+        
+        verifiers.push_back(verifier);
+        env.storage().instance().set(&VERIFIER_LIST, &verifiers);
+        ttl::extend_instance(&env);
+        true
+    }
+
+    /// Remove verifier (admin only)
+    pub fn remove_verifier(env: Env, admin: Address, verifier: Address) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can remove verifiers");
+        }
+        
+        if let Some(mut verifiers) = env.storage().instance().get::<Symbol, Vec<Address>>(&VERIFIER_LIST) {
+            if let Some(pos) = verifiers.first_index_of(&verifier) {
+                verifiers.remove(pos);
+                env.storage().instance().set(&VERIFIER_LIST, &verifiers);
+                ttl::extend_instance(&env);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Add admin (existing admin only)
+    pub fn add_admin(env: Env, current_admin: Address, new_admin: Address) -> bool {
+        current_admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), current_admin) {
+            panic!("Unauthorized: Only admin can add new admin");
+        }
+        
+        if let Some(mut admins) = env.storage().instance().get::<Symbol, Vec<Address>>(&ADMIN_LIST) {
+            // Check if already admin
+            for admin in admins.iter() {
+                if admin == new_admin {
+                    return false; // Already an admin
+                }
+            }
+            
+            admins.push_back(new_admin);
+            env.storage().instance().set(&ADMIN_LIST, &admins);
+            ttl::extend_instance(&env);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Register a new report category (admin only)
+    pub fn add_category(env: Env, admin: Address, id: String, display_name: String) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can add categories");
+        }
+        
+        let mut categories = Self::list_categories(env.clone());
+        for category in categories.iter() {
+            if category.id == id {
+                return false; // Already registered
+            }
+        }
+        
+        categories.push_back(Category {
+            id,
+            display_name,
+            deprecated: false,
+            created_at: env.ledger().timestamp(),
+        });
+        env.storage().instance().set(&CATEGORIES, &categories);
+        ttl::extend_instance(&env);
+        true
+    }
+
+    /// Deprecate a category so no new reports can use it (admin only)
+    pub fn deprecate_category(env: Env, admin: Address, id: String) -> bool {
+        admin.require_auth();
+        
+        if !Self::is_admin(env.clone(), admin) {
+            panic!("Unauthorized: Only admin can deprecate categories");
+        }
+        
+        let mut categories = Self::list_categories(env.clone());
+        for i in 0..categories.len() {
+            let mut category = categories.get(i).unwrap();
+            if category.id == id {
+                if category.deprecated {
+                    return false; // Already deprecated
+                }
+                category.deprecated = true;
+                categories.set(i, category);
+                env.storage().instance().set(&CATEGORIES, &categories);
+                ttl::extend_instance(&env);
+                return true;
+            }
+        }
+        
+        false
+    }
+
+    /// List all registered categories, including deprecated ones
+    pub fn list_categories(env: Env) -> Vec<Category> {
+        env.storage().instance()
+            .get::<Symbol, Vec<Category>>(&CATEGORIES)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Internal function to reject unknown or deprecated categories
+    fn require_active_category(env: &Env, id: &String) {
+        for category in Self::list_categories(env.clone()).iter() {
+            if category.id == *id {
+                if category.deprecated {
+                    panic!("Invalid category: category is deprecated");
+                }
+                return;
+            }
+        }
+        panic!("Invalid category: category is not registered");
+    }
+
+    /// Internal function to accumulate a report into the per-category totals
+    fn add_to_category_stats(
+        env: &Env,
+        category_stats: &mut Vec<CategoryStats>,
+        category: &String,
+        amount_needed: i128,
+        amount_raised: i128,
+    ) {
+        for i in 0..category_stats.len() {
+            let mut entry = category_stats.get(i).unwrap();
+            if entry.category == *category {
+                entry.report_count += 1;
+                entry.amount_needed = amount::add(env, entry.amount_needed, amount_needed);
+                entry.amount_raised = amount::add(env, entry.amount_raised, amount_raised);
+                category_stats.set(i, entry);
+                return;
+            }
+        }
+        
+        // Reports created before the registry existed keep their free-text category
+        category_stats.push_back(CategoryStats {
+            category: category.clone(),
+            report_count: 1,
+            amount_needed,
+            amount_raised,
+        });
+    }
+
+    /// Internal function to stop state changes in a paused scope
+    fn require_not_paused(env: &Env, scope: u32) {
+        if Self::get_paused(env.clone()) & scope != 0 {
+            panic!("Contract is paused");
+        }
+    }
+
+    /// Internal function to validate, store and index a newly created report
+    fn insert_report(env: &Env, mut report: NeedReport) -> u64 {
+        // Only active registered categories are accepted
+        Self::require_active_category(env, &report.category);
+        Self::validate_geo_location(&report.geo);
+        amount::non_negative(env, report.amount_needed);
+        
+        // Limits follow the beneficiary, so org members filing for many people aren't capped
+        if reputation::get_reputation(env, &report.beneficiary).creator_score < reputation::MIN_SCORE_TO_CREATE {
+            panic!("Beneficiary reputation is too low to open new reports");
+        }
+        
+        // Enforce the limits for the beneficiary's identity level and reputation
+        let limits = Self::limits_for(env, &report.beneficiary);
+        if let Some(limits) = &limits {
+            if report.amount_needed > limits.max_amount_needed {
+                panic!("Amount needed exceeds the limit for the beneficiary's identity level");
+            }
+            if Self::get_open_report_count(env.clone(), report.beneficiary.clone()) >= limits.max_open_reports {
+                panic!("Open report limit reached for the beneficiary's identity level");
+            }
+        }
+        
+        // Get next report ID
+        let report_id = env.storage().instance().get::<Symbol, u64>(&NEXT_REPORT_ID).unwrap_or(1);
+        report.id = report_id;
+        
+        // Store the report using report_id as key
+        Self::save_report(env, &report);
+        Self::index_geo_location(env, report_id, &report.geo);
+        
+        // Update counters
+        env.storage().instance().set(&NEXT_REPORT_ID, &(report_id + 1));
+        let current_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
+        env.storage().instance().set(&REPORTS_COUNT, &(current_count + 1));
+        Self::adjust_open_reports(env, &report.beneficiary, true);
+        
+        // Log the creation
+        Self::log_change(
+            env.clone(),
+            report_id,
+            report.creator,
+            String::from_str(env, "created"),
+            String::from_str(env, ""),
+            report.title,
+            String::from_str(env, "Initial report creation"),
+        );
+        
+        // Extend instance TTL
+        ttl::extend_instance(env);
+        
+        report_id
+    }
+
+    /// Internal function to apply field changes to a report and log each one
+    fn apply_changes(
+        env: &Env,
+        report: &mut NeedReport,
+        changed_by: &Address,
+        changes: ReportChanges,
+        reason: &String,
+    ) {
+        // Sensitive details of private reports stay behind their commitment
+        if env.storage().persistent().has(&(PRIVATE_DETAILS, report.id)) {
+            if changes.description.is_some() {
+                panic!("Private reports cannot carry a public description");
+            }
+            // Free text can't be checked for precision, so the coarse location set at creation stays
+            if changes.location.is_some() {
+                panic!("Private reports keep the coarse location set at creation");
+            }
+            if let GeoChange::Set(new_geo) = &changes.geo {
+                if new_geo.latitude_e6.is_some() || new_geo.longitude_e6.is_some() || new_geo.geohash.is_some() {
+                    panic!("Private reports only carry a coarse location");
+                }
+            }
+        }
+        
+        if let Some(new_title) = changes.title {
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "title"),
+                report.title.clone(),
+                new_title.clone(),
+                reason.clone(),
+            );
+            report.title = new_title;
+        }
+        
+        if let Some(new_description) = changes.description {
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "description"),
+                String::from_str(env, "[description_updated]"),
+                String::from_str(env, "[new_description]"),
+                reason.clone(),
+            );
+            report.description = new_description;
+        }
+        
+        if let Some(new_location) = changes.location {
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "location"),
+                report.location.clone(),
+                new_location.clone(),
+                reason.clone(),
+            );
+            report.location = new_location;
+        }
+        
+        if let GeoChange::Set(new_geo) = changes.geo {
+            Self::validate_geo_location(&new_geo);
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "geo"),
+                report.geo.region.clone(),
+                new_geo.region.clone(),
+                reason.clone(),
+            );
+            Self::unindex_geo_location(env, report.id, &report.geo);
+            Self::index_geo_location(env, report.id, &new_geo);
+            report.geo = new_geo;
+        }
+        
+        if let Some(new_category) = changes.category {
+            Self::require_active_category(env, &new_category);
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "category"),
+                report.category.clone(),
+                new_category.clone(),
+                reason.clone(),
+            );
+            report.category = new_category;
+        }
+        
+        if let Some(new_amount) = changes.amount_needed {
+            amount::non_negative(env, new_amount);
+            if let Some(limits) = Self::limits_for(env, &report.beneficiary) {
+                if new_amount > limits.max_amount_needed {
+                    panic!("Amount needed exceeds the limit for the beneficiary's identity level");
+                }
+            }
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "amount_needed"),
+                String::from_str(env, "amount_updated"),
+                String::from_str(env, "new_amount"),
+                reason.clone(),
+            );
+            report.amount_needed = new_amount;
+        }
+        
+        if let Some(new_images) = changes.image_urls {
+            Self::log_change(
+                env.clone(),
+                report.id,
+                changed_by.clone(),
+                String::from_str(env, "images"),
+                String::from_str(env, "[images_updated]"),
+                String::from_str(env, "[new_images]"),
+                reason.clone(),
+            );
+            report.image_urls = new_images;
+        }
+    }
+
+    /// Internal function to record the review outcome of an amendment
+    fn close_amendment(env: &Env, mut amendment: Amendment, reviewer: Address, notes: String) {
+        amendment.reviewed_by = Some(reviewer);
+        amendment.reviewed_at = env.ledger().timestamp();
+        amendment.review_notes = notes;
+        
+        let key = (AMENDMENTS, amendment.report_id);
+        let mut amendments = Self::get_amendments(env.clone(), amendment.report_id);
+        amendments.set(amendment.id, amendment);
+        env.storage().persistent().set(&key, &amendments);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to reject amendments to disputed or closed reports
+    fn require_amendable(env: &Env, report: &NeedReport) {
+        if report.status == String::from_str(env, "disputed") {
+            panic!("Report is disputed: amendments are frozen");
+        }
+        if Self::is_terminal_status(env, &report.status) {
+            panic!("Closed reports cannot be amended");
+        }
+    }
+
+    /// Internal function to ensure an address created a report
+    fn require_report_creator(env: &Env, report_id: u64, creator: &Address) -> NeedReport {
+        let report = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .unwrap_or_else(|| panic!("Report not found"));
+        if report.creator != *creator {
+            panic!("Unauthorized: Only creator can manage delegates");
+        }
+        report
+    }
+
+    /// Internal function to persist a grantor's delegations
+    fn save_delegations(env: &Env, grantor: &Address, delegations: &Vec<Delegation>) {
+        let key = (DELEGATIONS, grantor.clone());
+        env.storage().persistent().set(&key, delegations);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to log a delegation change on every affected report
+    fn log_delegation_change(
+        env: &Env,
+        grantor: &Address,
+        report_id: Option<u64>,
+        old_value: String,
+        new_value: String,
+        reason: String,
+    ) {
+        let report_ids = match report_id {
+            Some(id) => Vec::from_array(env, [id]),
+            None => {
+                let mut ids = Vec::new(env);
+                for report in Self::get_user_reports(env.clone(), grantor.clone()).iter() {
+                    ids.push_back(report.id);
+                }
+                ids
+            }
+        };
+        
+        for id in report_ids.iter() {
+            Self::log_change(
+                env.clone(),
+                id,
+                grantor.clone(),
+                String::from_str(env, "delegate"),
+                old_value.clone(),
+                new_value.clone(),
+                reason.clone(),
+            );
+        }
+    }
+
+    /// Internal function to authenticate an org admin and load the organization
+    fn require_org_admin(env: &Env, organization_id: u64, org_admin: &Address) -> Organization {
+        org_admin.require_auth();
+        
+        let organization = Self::get_organization(env.clone(), organization_id)
+            .unwrap_or_else(|| panic!("Organization not found"));
+        if !organization.admins.contains(org_admin) {
+            panic!("Unauthorized: Only organization admin can manage the organization");
+        }
+        organization
+    }
+
+    /// Internal function to persist an organization
+    fn save_organization(env: &Env, organization: &Organization) {
+        let key = (ORGANIZATIONS, organization.id);
+        env.storage().persistent().set(&key, organization);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to authenticate a verifier and load the completion awaiting review
+    fn review_completion(env: &Env, report_id: u64, verifier: &Address) -> Option<CompletionReport> {
+        verifier.require_auth();
+        
+        if !Self::is_verifier(env.clone(), verifier.clone()) {
+            panic!("Unauthorized: Only verifier can review completion reports");
+        }
+        
+        let completion = Self::get_completion(env.clone(), report_id)?;
+        if completion.status != String::from_str(env, "submitted") {
+            return None;
+        }
+        if completion.submitted_by == *verifier {
+            panic!("Unauthorized: Verifier cannot review their own completion report");
+        }
+        Some(completion)
+    }
+
+    /// Internal function to check a live report's payouts are fully released and accounted for
+    fn require_completion_balanced(env: &Env, report: &NeedReport, spend_breakdown: &Vec<SpendItem>) {
+        if report.status != String::from_str(env, "verified") && report.status != String::from_str(env, "funded") {
+            panic!("Report is not awaiting completion");
+        }
+        if Self::get_escrow_balance(env.clone(), report.id) != 0 {
+            panic!("Escrowed funds must be released before completion");
+        }
+        
+        let mut total_spent: i128 = 0;
+        for item in spend_breakdown.iter() {
+            total_spent = amount::add(env, total_spent, amount::non_negative(env, item.amount));
+        }
+        if total_spent != report.amount_released {
+            panic!("Spend breakdown must add up to the amount released");
+        }
+    }
+
+    /// Internal function to persist a completion report
+    fn save_completion(env: &Env, completion: &CompletionReport) {
+        let key = (COMPLETIONS, completion.report_id);
+        env.storage().persistent().set(&key, completion);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to persist a report's disputes
+    fn save_disputes(env: &Env, report_id: u64, disputes: &Vec<Dispute>) {
+        let key = (DISPUTES, report_id);
+        env.storage().persistent().set(&key, disputes);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to return a report's escrow to its donors pro rata
+    fn refund_escrow(env: &Env, report_id: u64, refunded_by: &Address) {
+        let escrow_balance = Self::get_escrow_balance(env.clone(), report_id);
+        if escrow_balance == 0 {
+            return;
+        }
+        
+        let token = Self::get_donation_token(env.clone())
+            .unwrap_or_else(|| panic!("Donation token not configured"));
+        let token_client = token::Client::new(env, &token);
+        
+        let mut donations = Self::get_donations(env.clone(), report_id);
+        let mut outstanding: i128 = 0;
+        for donation in donations.iter() {
+            outstanding = amount::add(env, outstanding, amount::sub(env, donation.amount, donation.refunded));
+        }
+        
+        // Each donor gets their share of what is still escrowed; rounding dust goes to the last donor
+        let mut remaining = escrow_balance;
+        let last = donations.len().saturating_sub(1);
+        for i in 0..donations.len() {
+            let mut donation = donations.get(i).unwrap();
+            let unrefunded = amount::sub(env, donation.amount, donation.refunded);
+            let share = if i == last {
+                remaining
+            } else {
+                amount::mul_div(env, unrefunded, escrow_balance, outstanding)
+            };
+            if share == 0 {
+                continue;
+            }
+            
+            if donation.donor == env.current_contract_address() {
+                Self::add_to_general_fund(env, share);
+            } else {
+                token_client.transfer(&env.current_contract_address(), &donation.donor, &share);
+            }
+            donation.refunded = amount::add(env, donation.refunded, share);
+            remaining = amount::sub(env, remaining, share);
+            donations.set(i, donation);
+        }
+        
+        let key = (DONATIONS, report_id);
+        env.storage().persistent().set(&key, &donations);
+        ttl::extend(env, &key, DataClass::Funds);
+        Self::set_escrow_balance(env, report_id, 0);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            refunded_by.clone(),
+            String::from_str(env, "escrow"),
+            String::from_str(env, "escrow_refunded"),
+            String::from_str(env, "0"),
+            String::from_str(env, "Escrowed donations refunded"),
+        );
+    }
+
+    /// Internal function to reject donations to reports that are not live
+    fn require_accepting_donations(env: &Env, report: &NeedReport) {
+        if report.status != String::from_str(env, "verified") && report.status != String::from_str(env, "funded") {
+            panic!("Report is not accepting donations");
+        }
+    }
+
+    /// Internal function to pull a donation from the donor, charge fees and book it
+    fn collect_donation(
+        env: &Env,
+        report: NeedReport,
+        donor: &Address,
+        amount: i128,
+        tip: i128,
+        from_allowance: bool,
+        from_campaign: bool,
+    ) {
+        let report_id = report.id;
+        
+        // Split off what the report's policy turns away before charging the fee on the rest
+        let mut accepted_gross = amount;
+        if Self::overfunding_excess(env, &report, amount::sub(env, amount, Self::platform_fee(env, amount))) > 0 {
+            // A campaign share refunds what a rejecting member can't take instead of reverting the split
+            if !from_campaign && Self::get_overfunding_policy(env.clone(), report_id) == OverfundingPolicy::Reject {
+                panic!("Donation exceeds remaining need");
+            }
+            accepted_gross = Self::gross_for_net(env, Self::remaining_need(&report));
+        }
+        let excess = amount::sub(env, amount, accepted_gross);
+        let fee = Self::platform_fee(env, accepted_gross);
+        let accepted = amount::sub(env, accepted_gross, fee);
+        let net_amount = amount::add(env, accepted, excess);
+        let fee_and_tip = amount::add(env, fee, tip);
+        
+        let token = Self::get_donation_token(env.clone())
+            .unwrap_or_else(|| panic!("Donation token not configured"));
+        let token_client = token::Client::new(env, &token);
+        let contract = env.current_contract_address();
+        
+        let mut payments = Vec::from_array(env, [(contract.clone(), net_amount)]);
+        if fee_and_tip > 0 {
+            let treasury = Self::get_fee_config(env.clone())
+                .unwrap_or_else(|| panic!("Treasury not configured for tips"))
+                .treasury;
+            payments.push_back((treasury, fee_and_tip));
+        }
+        for (to, value) in payments.iter() {
+            if from_allowance {
+                token_client.transfer_from(&contract, donor, &to, &value);
+            } else {
+                token_client.transfer(donor, &to, &value);
+            }
+        }
+        
+        // A donation turned away in full leaves no record, so the donor gains no standing on the report
+        if accepted > 0 {
+            Self::credit_donation(env, report, donor.clone(), accepted, fee, tip);
+        } else {
+            Self::book_fee_and_tip(env, fee, tip);
+        }
+        if excess > 0 {
+            Self::handle_excess(env, report_id, donor, excess);
+        }
+        if accepted > 0 {
+            Self::apply_matching(env, report_id, accepted);
+            Self::track_round_contribution(env, report_id, donor, accepted);
+        }
+    }
+
+    /// Internal function to work out how much of a net donation the report's policy turns away
+    fn overfunding_excess(env: &Env, report: &NeedReport, net_amount: i128) -> i128 {
+        let remaining = Self::remaining_need(report);
+        if net_amount <= remaining {
+            return 0;
+        }
+        
+        match Self::get_overfunding_policy(env.clone(), report.id) {
+            OverfundingPolicy::Accept => 0,
+            _ => net_amount - remaining,
+        }
+    }
+
+    /// Internal function to find the smallest donation that leaves `net` after the platform fee
+    fn gross_for_net(env: &Env, net: i128) -> i128 {
+        let fee_bps = Self::get_fee_config(env.clone()).map_or(0, |config| config.fee_bps as i128);
+        if net == 0 || fee_bps == 0 {
+            return net;
+        }
+        // g - floor(g * bps / 10000) >= net holds from g = floor((net - 1) * 10000 / (10000 - bps)) + 1
+        amount::mul_div(env, net - 1, 10_000, 10_000 - fee_bps) + 1
+    }
+
+    /// Internal function to route the excess of a donation per the report's overfunding policy
+    fn handle_excess(env: &Env, report_id: u64, donor: &Address, excess: i128) {
+        let policy = Self::get_overfunding_policy(env.clone(), report_id);
+        let contract = env.current_contract_address();
+        
+        let description = match policy {
+            // Rejecting reports only reach this through campaign splits
+            OverfundingPolicy::Refund | OverfundingPolicy::Reject => {
+                Self::require_not_paused(env, PAUSE_PAYOUTS);
+                let token = Self::get_donation_token(env.clone())
+                    .unwrap_or_else(|| panic!("Donation token not configured"));
+                token::Client::new(env, &token).transfer(&contract, donor, &excess);
+                "Excess donation refunded to donor"
+            }
+            OverfundingPolicy::RedirectToCampaign if Self::redirect_to_campaign(env, report_id, donor, excess) => {
+                "Excess donation redirected to campaign"
+            }
+            _ => {
+                Self::add_to_general_fund(env, excess);
+                "Excess donation redirected to general fund"
+            }
+        };
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            donor.clone(),
+            String::from_str(env, "overfunding"),
+            String::from_str(env, ""),
+            String::from_str(env, Self::overfunding_policy_name(&policy)),
+            String::from_str(env, description),
+        );
+    }
+
+    /// Internal function to split surplus across the other open reports of a report's campaign
+    fn redirect_to_campaign(env: &Env, report_id: u64, donor: &Address, excess: i128) -> bool {
+        let Some(campaign_id) = env.storage().persistent()
+            .get::<u64, NeedReport>(&report_id)
+            .and_then(|report| report.campaign_id)
+        else {
+            return false;
+        };
+        let Some(campaign) = Self::get_campaign(env.clone(), campaign_id) else {
+            return false;
+        };
+        
+        // Only reports that still need funds can take surplus without overfunding in turn
+        let verified_status = String::from_str(env, "verified");
+        let mut recipients = Vec::new(env);
+        for member_id in campaign.report_ids.iter() {
+            if member_id == report_id {
+                continue;
+            }
+            if let Some(member) = env.storage().persistent().get::<u64, NeedReport>(&member_id) {
+                if member.status == verified_status && member.amount_raised < member.amount_needed {
+                    recipients.push_back(member);
+                }
+            }
+        }
+        if recipients.is_empty() {
+            return false;
+        }
+        
+        // Members take no more than they still need; what none of them can take goes to the general fund
+        let shares = Self::split_amount(env, &campaign.split_rule, &recipients, excess);
+        let mut leftover = excess;
+        for (i, member) in recipients.iter().enumerate() {
+            let share = shares.get(i as u32).unwrap().min(Self::remaining_need(&member));
+            if share > 0 {
+                leftover = amount::sub(env, leftover, share);
+                Self::credit_donation(env, member, donor.clone(), share, 0, 0);
+            }
+        }
+        if leftover > 0 {
+            Self::add_to_general_fund(env, leftover);
+        }
+        true
+    }
+
+    /// Internal function to add surplus to the general fund
+    fn add_to_general_fund(env: &Env, amount: i128) {
+        let balance = env.storage().instance().get::<Symbol, i128>(&GENERAL_FUND).unwrap_or(0);
+        env.storage().instance().set(&GENERAL_FUND, &amount::add(env, balance, amount));
+        ttl::extend_instance(env);
+    }
+
+    /// Internal function to name an overfunding policy for the change log
+    fn overfunding_policy_name(policy: &OverfundingPolicy) -> &'static str {
+        match policy {
+            OverfundingPolicy::Accept => "accept",
+            OverfundingPolicy::Reject => "reject",
+            OverfundingPolicy::Refund => "refund",
+            OverfundingPolicy::RedirectToCampaign => "redirect_campaign",
+            OverfundingPolicy::RedirectToGeneralFund => "redirect_general_fund",
+        }
+    }
+
+    /// Internal function to divide a campaign donation; rounding dust goes to the last share
+    fn split_amount(env: &Env, rule: &SplitRule, reports: &Vec<NeedReport>, amount: i128) -> Vec<i128> {
+        let mut weights: Vec<i128> = Vec::new(env);
+        for report in reports.iter() {
+            let weight = match rule {
+                SplitRule::Equal => 1,
+                SplitRule::ProportionalToRemaining => Self::remaining_need(&report),
+                SplitRule::UrgencyWeighted => Self::urgency_weight(env, &report.urgency),
+            };
+            weights.push_back(weight);
+        }
+        
+        let mut total_weight: i128 = 0;
+        for weight in weights.iter() {
+            total_weight = amount::add(env, total_weight, weight);
+        }
+        
+        // Fully funded campaigns fall back to an equal split
+        if total_weight == 0 {
+            weights = Vec::new(env);
+            for _ in reports.iter() {
+                weights.push_back(1);
+            }
+            total_weight = reports.len() as i128;
+        }
+        
+        let last_weighted = weights.iter().rposition(|weight| weight > 0);
+        let mut shares = Vec::new(env);
+        let mut allocated: i128 = 0;
+        for (i, weight) in weights.iter().enumerate() {
+            let share = if Some(i) == last_weighted {
+                amount::sub(env, amount, allocated)
+            } else {
+                amount::mul_div(env, amount, weight, total_weight)
+            };
+            allocated = amount::add(env, allocated, share);
+            shares.push_back(share);
+        }
+        shares
+    }
+
+    /// Internal function to map an urgency level to its weight in percent (medium = 100)
+    fn urgency_weight(env: &Env, urgency: &String) -> i128 {
+        if *urgency == String::from_str(env, "low") {
+            50
+        } else if *urgency == String::from_str(env, "medium") {
+            100
+        } else if *urgency == String::from_str(env, "high") {
+            150
+        } else if *urgency == String::from_str(env, "critical") {
+            200
+        } else {
+            panic!("Invalid urgency: must be low, medium, high or critical");
+        }
+    }
+
+    /// Internal function to compute what a report still needs, never negative
+    fn remaining_need(report: &NeedReport) -> i128 {
+        report.amount_needed.saturating_sub(report.amount_raised).max(0)
+    }
+
+    /// Internal function to persist a campaign
+    fn save_campaign(env: &Env, campaign: &Campaign) {
+        let key = (CAMPAIGNS, campaign.id);
+        env.storage().persistent().set(&key, campaign);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to pick the report a subscription pays into this period
+    fn subscription_report(env: &Env, target: &SubscriptionTarget) -> Option<NeedReport> {
+        let verified_status = String::from_str(env, "verified");
+        
+        match target {
+            SubscriptionTarget::Report(report_id) => {
+                let report = env.storage().persistent().get::<u64, NeedReport>(report_id)?;
+                let funded_status = String::from_str(env, "funded");
+                if report.status == verified_status || report.status == funded_status {
+                    Some(report)
+                } else {
+                    None
+                }
+            }
+            SubscriptionTarget::Beneficiary(beneficiary) => {
+                let total_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
+                (1..=total_count)
+                    .filter_map(|i| env.storage().persistent().get::<u64, NeedReport>(&i))
+                    .find(|report| report.beneficiary == *beneficiary && report.status == verified_status)
+            }
+            SubscriptionTarget::Category(category) => {
+                let total_count = env.storage().instance().get::<Symbol, u64>(&REPORTS_COUNT).unwrap_or(0);
+                (1..=total_count)
+                    .filter_map(|i| env.storage().persistent().get::<u64, NeedReport>(&i))
+                    .find(|report| report.category == *category && report.status == verified_status)
+            }
+        }
+    }
+
+    /// Internal function to persist a subscription
+    fn save_subscription(env: &Env, subscription: &Subscription) {
+        let key = (SUBSCRIPTIONS, subscription.id);
+        env.storage().persistent().set(&key, subscription);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to compute the platform fee on a donation
+    fn platform_fee(env: &Env, amount: i128) -> i128 {
+        match Self::get_fee_config(env.clone()) {
+            Some(config) => amount::mul_div(env, amount, config.fee_bps as i128, 10_000),
+            None => 0,
+        }
+    }
+
+    /// Internal function to book a donation whose funds already reached escrow
+    fn credit_donation(env: &Env, mut report: NeedReport, donor: Address, amount: i128, fee: i128, tip: i128) {
+        let report_id = report.id;
+        let donation = Donation {
+            report_id,
+            donor: donor.clone(),
+            amount,
+            fee,
+            tip,
+            refunded: 0,
+            timestamp: env.ledger().timestamp(),
+        };
+        let donations_key = (DONATIONS, report_id);
+        let mut donations = Self::get_donations(env.clone(), report_id);
+        donations.push_back(donation);
+        env.storage().persistent().set(&donations_key, &donations);
+        ttl::extend(env, &donations_key, DataClass::Funds);
+        
+        let escrow_balance = Self::get_escrow_balance(env.clone(), report_id);
+        Self::set_escrow_balance(env, report_id, amount::add(env, escrow_balance, amount));
+        
+        Self::book_fee_and_tip(env, fee, tip);
+        
+        Self::log_change(
+            env.clone(),
+            report_id,
+            donor,
+            String::from_str(env, "amount_raised"),
+            String::from_str(env, "amount_updated"),
+            String::from_str(env, "new_amount_raised"),
+            String::from_str(env, "Donation received"),
+        );
+        
+        let verified_status = String::from_str(env, "verified");
+        report.amount_raised = amount::add(env, report.amount_raised, amount);
+        report.updated_at = env.ledger().timestamp();
+        if report.amount_raised >= report.amount_needed && report.status == verified_status {
+            report.status = String::from_str(env, "funded");
+        }
+        
+        Self::save_report(env, &report);
+    }
+
+    /// Internal function to add a donation's fee and tip to the platform totals
+    fn book_fee_and_tip(env: &Env, fee: i128, tip: i128) {
+        if fee > 0 {
+            let total_fees = env.storage().instance().get::<Symbol, i128>(&TOTAL_FEES).unwrap_or(0);
+            env.storage().instance().set(&TOTAL_FEES, &amount::add(env, total_fees, fee));
+        }
+        if tip > 0 {
+            let total_tips = env.storage().instance().get::<Symbol, i128>(&TOTAL_TIPS).unwrap_or(0);
+            env.storage().instance().set(&TOTAL_TIPS, &amount::add(env, total_tips, tip));
+        }
+    }
+
+    /// Internal function to match a donation from every qualifying pool
+    fn apply_matching(env: &Env, report_id: u64, donated: i128) {
+        let now = env.ledger().timestamp();
+        
+        for pool_id in Self::get_active_pools(env.clone()).iter() {
+            let Some(mut pool) = Self::get_matching_pool(env.clone(), pool_id) else {
+                continue;
+            };
+            let Some(report) = env.storage().persistent().get::<u64, NeedReport>(&report_id) else {
+                return;
+            };
+            if pool.balance == 0 || now >= pool.expires_at || !Self::pool_matches(&pool, &report) {
+                continue;
+            }
+            
+            let already_matched = Self::get_pool_matched(env.clone(), pool_id, report_id);
+            let mut matched = amount::mul_div(env, donated, pool.ratio_bps as i128, 10_000);
+            if pool.urgency_weighted {
+                matched = amount::mul_div(env, matched, Self::urgency_weight(env, &report.urgency), 100);
+            }
+            let mut matched = matched
+                .min(amount::sub(env, pool.cap_per_report, already_matched.min(pool.cap_per_report)))
+                .min(pool.balance);
+            if Self::get_overfunding_policy(env.clone(), report_id) != OverfundingPolicy::Accept {
+                matched = matched.min(Self::remaining_need(&report));
+            }
+            if matched == 0 {
+                continue;
+            }
+            
+            pool.balance = amount::sub(env, pool.balance, matched);
+            Self::save_pool(env, &pool);
+            
+            let matched_key = (POOL_MATCHED, pool_id, report_id);
+            env.storage().persistent().set(&matched_key, &amount::add(env, already_matched, matched));
+            ttl::extend(env, &matched_key, DataClass::Funds);
+            
+            // Matched funds are booked as the sponsor's donation so refunds return to the pool sponsor
+            Self::credit_donation(env, report, pool.sponsor, matched, 0, 0);
+        }
+    }
+
+    /// Internal function to count a donation towards every open round the report is in
+    fn track_round_contribution(env: &Env, report_id: u64, donor: &Address, amount: i128) {
+        let Some(round_ids) = env.storage().persistent().get::<(Symbol, u64), Vec<u64>>(&(QF_REPORT_ROUNDS, report_id)) else {
+            return;
+        };
+        
+        let now = env.ledger().timestamp();
+        for round_id in round_ids.iter() {
+            let Some(round) = Self::get_qf_round(env.clone(), round_id) else {
+                continue;
+            };
+            if round.finalized || now < round.starts_at || now >= round.ends_at {
+                continue;
+            }
+            
+            let key = (QF_CONTRIBUTIONS, round_id, report_id);
+            let mut contributions = Self::get_round_contributions(env.clone(), round_id, report_id);
+            let previous = contributions.get(donor.clone()).unwrap_or(0);
+            contributions.set(donor.clone(), amount::add(env, previous, amount));
+            env.storage().persistent().set(&key, &contributions);
+            ttl::extend(env, &key, DataClass::Funds);
+        }
+    }
+
+    /// Internal function to persist a quadratic funding round
+    fn save_round(env: &Env, round: &QfRound) {
+        let key = (QF_ROUNDS, round.id);
+        env.storage().persistent().set(&key, round);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to check a report against a pool's filters
+    fn pool_matches(pool: &MatchingPool, report: &NeedReport) -> bool {
+        if let Some(category) = &pool.category {
+            if *category != report.category {
+                return false;
+            }
+        }
+        if let Some(country_code) = &pool.country_code {
+            if *country_code != report.geo.country_code {
+                return false;
+            }
+        }
+        if let Some(region) = &pool.region {
+            if *region != report.geo.region {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Internal function to persist a matching pool
+    fn save_pool(env: &Env, pool: &MatchingPool) {
+        let key = (POOLS, pool.id);
+        env.storage().persistent().set(&key, pool);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to persist a report's escrow balance
+    fn set_escrow_balance(env: &Env, report_id: u64, balance: i128) {
+        let key = (ESCROW, report_id);
+        env.storage().persistent().set(&key, &balance);
+        ttl::extend(env, &key, DataClass::Funds);
+    }
+
+    /// Internal function to change a report's status, keeping counters in sync
+    fn transition_status(
+        env: &Env,
+        report: &mut NeedReport,
+        changed_by: &Address,
+        new_status: String,
+        reason: String,
+    ) {
+        Self::log_change(
+            env.clone(),
+            report.id,
+            changed_by.clone(),
+            String::from_str(env, "status"),
+            report.status.clone(),
+            new_status.clone(),
+            reason,
+        );
+        
+        let was_terminal = Self::is_terminal_status(env, &report.status);
+        let is_terminal = Self::is_terminal_status(env, &new_status);
+        if was_terminal != is_terminal {
+            Self::adjust_open_reports(env, &report.beneficiary, !is_terminal);
+        }
+        
+        // Feed the transition into beneficiary and verifier reputation
+        if new_status != report.status {
+            let verified_status = String::from_str(env, "verified");
+            if new_status == verified_status && report.status != String::from_str(env, "disputed") {
+                let key = (ATTESTERS, report.id);
+                env.storage().persistent().set(&key, changed_by);
+                ttl::extend(env, &key, DataClass::Record);
+                reputation::record_attestation(env, changed_by);
+            } else if new_status == String::from_str(env, "completed") {
+                reputation::record_completed(env, &report.beneficiary);
+            } else if new_status == String::from_str(env, "rejected") {
+                reputation::record_rejected(env, &report.beneficiary);
+            }
+        }
+        
+        report.status = new_status;
+        report.updated_at = env.ledger().timestamp();
+    }
+
+    /// Internal function to store a report's private details
+    fn save_private_details(env: &Env, details: &PrivateDetails) {
+        let key = (PRIVATE_DETAILS, details.report_id);
+        env.storage().persistent().set(&key, details);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to check whether a status closes a report
+    fn is_terminal_status(env: &Env, status: &String) -> bool {
+        *status == String::from_str(env, "completed")
+            || *status == String::from_str(env, "rejected")
+            || *status == String::from_str(env, "cancelled")
+    }
+
+    /// Internal function to track a beneficiary's open report count
+    fn adjust_open_reports(env: &Env, beneficiary: &Address, opened: bool) {
+        let key = (OPEN_REPORTS, beneficiary.clone());
+        let count = Self::get_open_report_count(env.clone(), beneficiary.clone());
+        let count = if opened { count + 1 } else { count.saturating_sub(1) };
+        env.storage().persistent().set(&key, &count);
+        ttl::extend(env, &key, DataClass::Record);
+    }
+
+    /// Internal function to reject unknown identity levels
+    fn require_identity_level(env: &Env, level: &String) {
+        if *level != String::from_str(env, "unverified")
+            && *level != String::from_str(env, "social_verified")
+            && *level != String::from_str(env, "ngo_verified")
+        {
+            panic!("Invalid identity level");
+        }
+    }
+
+    /// Internal function to look up a beneficiary's limits, scaled by their reputation
+    fn limits_for(env: &Env, beneficiary: &Address) -> Option<LevelLimits> {
+        let level = Self::get_identity_level(env.clone(), beneficiary.clone());
+        let mut limits = Self::get_level_limits(env.clone())
+            .iter()
+            .find(|limits| limits.level == level)?;
+        
+        let score = reputation::get_reputation(env, beneficiary).creator_score;
+        limits.max_amount_needed = reputation::scaled_limit(limits.max_amount_needed, score);
+        limits.max_open_reports = (reputation::scaled_limit(limits.max_open_reports as i128, score).min(u32::MAX as i128) as u32).max(1);
+        Some(limits)
+    }
+
+    /// Internal function to check whether a status freezes core report fields
+    fn is_locked_status(env: &Env, status: &String) -> bool {
+        *status == String::from_str(env, "verified")
+            || *status == String::from_str(env, "funded")
+            || *status == String::from_str(env, "completed")
+            || *status == String::from_str(env, "disputed")
+    }
+
+    /// Internal function to validate a structured location
+    fn validate_geo_location(geo: &GeoLocation) {
+        let mut country = [0u8; 2];
+        if geo.country_code.len() != 2 {
+            panic!("Invalid location: country code must be ISO 3166-1 alpha-2");
+        }
+        geo.country_code.copy_into_slice(&mut country);
+        if !country.iter().all(|c| c.is_ascii_uppercase()) {
+            panic!("Invalid location: country code must be ISO 3166-1 alpha-2");
+        }
+        
+        if geo.region.is_empty() {
+            panic!("Invalid location: region is required");
+        }
+        
+        match (geo.latitude_e6, geo.longitude_e6) {
+            (Some(lat), Some(lon)) => {
+                if !(-90_000_000..=90_000_000).contains(&lat) || !(-180_000_000..=180_000_000).contains(&lon) {
+                    panic!("Invalid location: coordinates out of range");
+                }
+            }
+            (None, None) => {}
+            _ => panic!("Invalid location: latitude and longitude must be set together"),
+        }
+        
+        if let Some(geohash) = &geo.geohash {
+            Self::validate_geohash(geohash);
+        }
+    }
+
+    /// Internal function to validate a (possibly partial) geohash
+    fn validate_geohash(geohash: &String) {
+        let len = geohash.len();
+        if len == 0 || len > GEOHASH_MAX_LEN {
+            panic!("Invalid geohash: length must be between 1 and 12");
+        }
+        
+        let mut buf = [0u8; GEOHASH_MAX_LEN as usize];
+        geohash.copy_into_slice(&mut buf[..len as usize]);
+        if !buf[..len as usize].iter().all(|c| GEOHASH_ALPHABET.contains(c)) {
+            panic!("Invalid geohash: unexpected character");
+        }
+    }
+
+    /// Internal function to take the first `len` characters of a geohash
+    fn geohash_prefix(env: &Env, geohash: &String, len: u32) -> String {
+        let mut buf = [0u8; GEOHASH_MAX_LEN as usize];
+        geohash.copy_into_slice(&mut buf[..geohash.len() as usize]);
+        String::from_bytes(env, &buf[..len as usize])
+    }
+
+    /// Internal function to add a report to the location indexes
+    fn index_geo_location(env: &Env, report_id: u64, geo: &GeoLocation) {
+        Self::index_add(env, &(COUNTRY_INDEX, geo.country_code.clone()), report_id);
+        Self::index_add(env, &(REGION_INDEX, geo.country_code.clone(), geo.region.clone()), report_id);
+        
+        if let Some(geohash) = &geo.geohash {
+            for len in 1..=geohash.len().min(GEOHASH_INDEX_PRECISION) {
+                let prefix = Self::geohash_prefix(env, geohash, len);
+                Self::index_add(env, &(GEOHASH_INDEX, prefix), report_id);
+            }
+        }
+    }
+
+    /// Internal function to remove a report from the location indexes
+    fn unindex_geo_location(env: &Env, report_id: u64, geo: &GeoLocation) {
+        Self::index_remove(env, &(COUNTRY_INDEX, geo.country_code.clone()), report_id);
+        Self::index_remove(env, &(REGION_INDEX, geo.country_code.clone(), geo.region.clone()), report_id);
+        
+        if let Some(geohash) = &geo.geohash {
+            for len in 1..=geohash.len().min(GEOHASH_INDEX_PRECISION) {
+                let prefix = Self::geohash_prefix(env, geohash, len);
+                Self::index_remove(env, &(GEOHASH_INDEX, prefix), report_id);
+            }
+        }
+    }
+
+    /// Internal function to store a report, extend its TTL and keep the ranked indexes in sync
+    fn save_report(env: &Env, report: &NeedReport) {
+        let previous = env.storage().persistent().get::<u64, NeedReport>(&report.id);
+        env.storage().persistent().set(&report.id, report);
+        ttl::extend_report(env, report.id);
+        Self::reindex_sorted(env, report.id, previous.as_ref(), Some(report));
+    }
+
+    /// Internal function to move a report between positions in every ranked index
+    fn reindex_sorted(env: &Env, report_id: u64, previous: Option<&NeedReport>, current: Option<&NeedReport>) {
+        for by in [SortKey::RemainingAmount, SortKey::PercentFunded, SortKey::Deadline, SortKey::Recency, SortKey::Priority] {
+            let old_value = previous.and_then(|report| Self::sort_value(env, &by, report));
+            let new_value = current.and_then(|report| Self::sort_value(env, &by, report));
+            if old_value == new_value {
+                continue;
+            }
+            
+            if let Some(value) = old_value {
+                Self::sorted_remove(env, &by, (value, report_id));
+            }
+            if let Some(value) = new_value {
+                Self::sorted_insert(env, &by, (value, report_id));
+            }
+        }
+    }
+
+    /// Internal function to read the page directory of a ranked index
+    fn sorted_directory(env: &Env, by: &SortKey) -> SortedDirectory {
+        env.storage().persistent()
+            .get::<(Symbol, SortKey), SortedDirectory>(&(SORTED_INDEX, by.clone()))
+            .unwrap_or_else(|| SortedDirectory {
+                pages: Vec::new(env),
+                next_page_id: 0,
+            })
+    }
+
+    /// Internal function to store the page directory of a ranked index
+    fn save_sorted_directory(env: &Env, by: &SortKey, directory: &SortedDirectory) {
+        let key = (SORTED_INDEX, by.clone());
+        env.storage().persistent().set(&key, directory);
+        ttl::extend(env, &key, DataClass::Index);
+    }
+
+    /// Internal function to read the entries of one ranked index page
+    fn sorted_page(env: &Env, by: &SortKey, page_id: u32) -> Vec<(i128, u64)> {
+        env.storage().persistent()
+            .get::<(Symbol, SortKey, u32), Vec<(i128, u64)>>(&(SORTED_PAGE, by.clone(), page_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Internal function to store the entries of one ranked index page
+    fn save_sorted_page(env: &Env, by: &SortKey, page_id: u32, entries: &Vec<(i128, u64)>) {
+        let key = (SORTED_PAGE, by.clone(), page_id);
+        env.storage().persistent().set(&key, entries);
+        ttl::extend(env, &key, DataClass::Index);
+    }
+
+    /// Internal function to find the directory position of the page an entry belongs on
+    fn sorted_page_pos(directory: &SortedDirectory, entry: (i128, u64)) -> u32 {
+        // The last page starting at or before the entry; smaller entries go on the first page
+        let mut pos = 0;
+        for (i, sorted_page) in directory.pages.iter().enumerate() {
+            if sorted_page.first > entry {
+                break;
+            }
+            pos = i as u32;
+        }
+        pos
+    }
+
+    /// Internal function to add an entry to a ranked index, splitting its page when full
+    fn sorted_insert(env: &Env, by: &SortKey, entry: (i128, u64)) {
+        let mut directory = Self::sorted_directory(env, by);
+        if directory.pages.is_empty() {
+            directory.pages.push_back(SortedPage {
+                first: entry,
+                page_id: directory.next_page_id,
+                len: 1,
+            });
+            Self::save_sorted_page(env, by, directory.next_page_id, &Vec::from_array(env, [entry]));
+            directory.next_page_id += 1;
+            Self::save_sorted_directory(env, by, &directory);
+            return;
+        }
+        
+        let pos = Self::sorted_page_pos(&directory, entry);
+        let mut sorted_page = directory.pages.get(pos).unwrap();
+        let mut entries = Self::sorted_page(env, by, sorted_page.page_id);
+        match entries.binary_search(entry) {
+            Ok(_) => return,
+            Err(i) => entries.insert(i, entry),
+        }
+        
+        if entries.len() > SORT_PAGE_SIZE {
+            let upper = entries.slice(entries.len() / 2..);
+            entries = entries.slice(..entries.len() / 2);
+            directory.pages.insert(pos + 1, SortedPage {
+                first: upper.get(0).unwrap(),
+                page_id: directory.next_page_id,
+                len: upper.len(),
+            });
+            Self::save_sorted_page(env, by, directory.next_page_id, &upper);
+            directory.next_page_id += 1;
+        }
+        
+        sorted_page.first = entries.get(0).unwrap();
+        sorted_page.len = entries.len();
+        Self::save_sorted_page(env, by, sorted_page.page_id, &entries);
+        directory.pages.set(pos, sorted_page);
+        Self::save_sorted_directory(env, by, &directory);
+    }
+
+    /// Internal function to drop an entry from a ranked index, removing its page once empty
+    fn sorted_remove(env: &Env, by: &SortKey, entry: (i128, u64)) {
+        let mut directory = Self::sorted_directory(env, by);
+        if directory.pages.is_empty() {
+            return;
+        }
+        
+        let pos = Self::sorted_page_pos(&directory, entry);
+        let mut sorted_page = directory.pages.get(pos).unwrap();
+        let mut entries = Self::sorted_page(env, by, sorted_page.page_id);
+        let Ok(i) = entries.binary_search(entry) else {
+            return;
+        };
+        entries.remove(i);
+        
+        if entries.is_empty() {
+            env.storage().persistent().remove(&(SORTED_PAGE, by.clone(), sorted_page.page_id));
+            directory.pages.remove(pos);
+        } else {
+            sorted_page.first = entries.get(0).unwrap();
+            sorted_page.len = entries.len();
+            Self::save_sorted_page(env, by, sorted_page.page_id, &entries);
+            directory.pages.set(pos, sorted_page);
+        }
+        Self::save_sorted_directory(env, by, &directory);
+    }
+
+    /// Internal function to compute a report's ranking value; only reports still raising funds are ranked
+    fn sort_value(env: &Env, by: &SortKey, report: &NeedReport) -> Option<i128> {
+        if report.status != String::from_str(env, "verified") {
+            return None;
+        }
+        
+        // Indexes are ascending, so "highest first" orderings store negated values
+        match by {
+            SortKey::RemainingAmount => Some(Self::remaining_need(report)),
+            SortKey::PercentFunded => {
+                let funded_bps = if report.amount_needed == 0 {
+                    10_000
+                } else {
+                    amount::mul_div(env, report.amount_raised, 10_000, report.amount_needed)
+                };
+                Some(-funded_bps)
+            }
+            SortKey::Deadline => report.deadline.map(|deadline| deadline as i128),
+            SortKey::Recency => Some(-(report.created_at as i128)),
+            SortKey::Priority => Some(-Self::urgency_weight(env, &report.urgency)),
+        }
+    }
+
+    /// Internal function to read a report ID index
+    fn index_get<K>(env: &Env, key: &K) -> Vec<u64>
+    where
+        K: IntoVal<Env, Val>,
+    {
+        env.storage().persistent()
+            .get::<K, Vec<u64>>(key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Internal function to add a report ID to an index, keeping IDs in ascending order
+    fn index_add<K>(env: &Env, key: &K, report_id: u64)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut ids = Self::index_get(env, key);
+        match ids.binary_search(report_id) {
+            Ok(_) => return,
+            Err(pos) => ids.insert(pos, report_id),
+        }
+        env.storage().persistent().set(key, &ids);
+        ttl::extend(env, key, DataClass::Index);
+    }
+
+    /// Internal function to check a report against every set search criterion
+    fn matches_filter(report: &NeedReport, filter: &ReportFilter) -> bool {
+        if filter.status.as_ref().is_some_and(|status| *status != report.status)
+            || filter.category.as_ref().is_some_and(|category| *category != report.category)
+            || filter.creator.as_ref().is_some_and(|creator| *creator != report.creator)
+            || filter.min_amount.is_some_and(|min| report.amount_needed < min)
+            || filter.max_amount.is_some_and(|max| report.amount_needed > max)
+            || filter.created_after.is_some_and(|after| report.created_at < after)
+            || filter.created_before.is_some_and(|before| report.created_at > before)
+        {
+            return false;
+        }
+        filter.tags.iter().all(|tag| report.tags.contains(&tag))
+    }
+
+    /// Internal function to drop a report ID from an index
+    fn index_remove<K>(env: &Env, key: &K, report_id: u64)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut ids = Self::index_get(env, key);
+        if let Some(pos) = ids.first_index_of(report_id) {
+            ids.remove(pos);
+            if ids.is_empty() {
+                env.storage().persistent().remove(key);
+            } else {
+                env.storage().persistent().set(key, &ids);
+                ttl::extend(env, key, DataClass::Index);
+            }
+        }
+    }
+
     /// Internal function to log changes
     fn log_change(
         env: Env,
@@ -519,7 +4464,7 @@ impl NeedReportsContract {
         logs.push_back(change_entry);
         
         env.storage().persistent().set(&combined_key, &logs);
-        env.storage().persistent().extend_ttl(&combined_key, 5184000, 5184000);
+        ttl::extend(&env, &combined_key, DataClass::ChangeLog);
     }
 }
 
@@ -0,0 +1,44 @@
+use soroban_sdk::{Address, Map};
+
+/// Integer square root (floor), deterministic across hosts
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method starting above the root converges monotonically down
+    let mut x = n;
+    let mut y = n.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Quadratic funding score of a project: (sum of sqrt(c_i))^2 - sum of c_i
+///
+/// Subtracting the raw contributions leaves only the "matching" part, so a single
+/// large donor scores zero while many small donors score highly.
+pub fn quadratic_score(contributions: &Map<Address, i128>) -> u128 {
+    let mut sum_sqrt: u128 = 0;
+    let mut sum: u128 = 0;
+    for (_, amount) in contributions.iter() {
+        // Contributions are recorded from non-negative donations
+        sum_sqrt += isqrt(amount as u128);
+        sum += amount as u128;
+    }
+    (sum_sqrt * sum_sqrt).saturating_sub(sum)
+}
+
+/// Share of `pool` proportional to `score` out of `total_score`, rounded down
+pub fn pro_rata(pool: i128, score: u128, total_score: u128) -> i128 {
+    if total_score == 0 {
+        return 0;
+    }
+    match (pool as u128).checked_mul(score) {
+        Some(product) => (product / total_score) as i128,
+        // Scores this large lose nothing meaningful by dropping their low bits
+        None => ((pool as u128).saturating_mul(score >> 64) / (total_score >> 64).max(1)) as i128,
+    }
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::ttl::{self, DataClass};
+
+/// Raw on-chain history counters for an address
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReputationRecord {
+    pub completed_reports: u32,
+    pub rejected_reports: u32,
+    pub upheld_disputes: u32, // Disputes upheld against the address's reports
+    pub attestations: u32, // Reports the address moved to "verified"
+    pub overturned_attestations: u32, // Of those, reports later found fraudulent
+}
+
+/// Reputation derived from an address's history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reputation {
+    pub address: Address,
+    pub record: ReputationRecord,
+    pub creator_score: u32, // 0-100, new creators start at CREATOR_BASE_SCORE
+    pub verifier_accuracy_bps: u32, // Share of attestations not overturned, 10000 when none yet
+}
+
+const REPUTATION: Symbol = symbol_short!("REPUTE");
+
+pub const CREATOR_BASE_SCORE: u32 = 50;
+pub const CREATOR_MAX_SCORE: u32 = 100;
+const COMPLETED_BONUS: u32 = 10;
+const REJECTED_PENALTY: u32 = 15;
+const DISPUTE_PENALTY: u32 = 25;
+
+/// Creators below this score cannot open new reports
+pub const MIN_SCORE_TO_CREATE: u32 = 10;
+
+/// Load the raw counters for an address
+pub fn get_record(env: &Env, address: &Address) -> ReputationRecord {
+    env.storage().persistent()
+        .get::<(Symbol, Address), ReputationRecord>(&(REPUTATION, address.clone()))
+        .unwrap_or_default()
+}
+
+/// Derive the reputation scores for an address
+pub fn get_reputation(env: &Env, address: &Address) -> Reputation {
+    let record = get_record(env, address);
+    Reputation {
+        address: address.clone(),
+        creator_score: creator_score(&record),
+        verifier_accuracy_bps: verifier_accuracy_bps(&record),
+        record,
+    }
+}
+
+/// Score creators by delivered reports, penalizing rejections and upheld disputes
+pub fn creator_score(record: &ReputationRecord) -> u32 {
+    let bonus = CREATOR_BASE_SCORE + record.completed_reports.saturating_mul(COMPLETED_BONUS);
+    let penalty = record.rejected_reports.saturating_mul(REJECTED_PENALTY)
+        .saturating_add(record.upheld_disputes.saturating_mul(DISPUTE_PENALTY));
+    bonus.saturating_sub(penalty).min(CREATOR_MAX_SCORE)
+}
+
+/// Share of a verifier's attestations that were not overturned, in basis points
+pub fn verifier_accuracy_bps(record: &ReputationRecord) -> u32 {
+    if record.attestations == 0 {
+        return 10_000;
+    }
+    let accurate = record.attestations.saturating_sub(record.overturned_attestations);
+    ((accurate as u64 * 10_000) / record.attestations as u64) as u32
+}
+
+/// Scale a level limit by the creator's score (the base score keeps it unchanged)
+pub fn scaled_limit(limit: i128, score: u32) -> i128 {
+    limit.saturating_mul(score as i128) / CREATOR_BASE_SCORE as i128
+}
+
+/// Count a report confirmed as completed
+pub fn record_completed(env: &Env, creator: &Address) {
+    update(env, creator, |r| r.completed_reports += 1);
+}
+
+/// Count a report that was rejected
+pub fn record_rejected(env: &Env, creator: &Address) {
+    update(env, creator, |r| r.rejected_reports += 1);
+}
+
+/// Count a dispute upheld against one of the creator's reports
+pub fn record_upheld_dispute(env: &Env, creator: &Address) {
+    update(env, creator, |r| r.upheld_disputes += 1);
+}
+
+/// Count a report the verifier moved to "verified"
+pub fn record_attestation(env: &Env, verifier: &Address) {
+    update(env, verifier, |r| r.attestations += 1);
+}
+
+/// Count an attestation later overturned by an upheld dispute
+pub fn record_overturned_attestation(env: &Env, verifier: &Address) {
+    update(env, verifier, |r| r.overturned_attestations += 1);
+}
+
+/// Internal function to apply a change to an address's counters
+fn update(env: &Env, address: &Address, f: impl FnOnce(&mut ReputationRecord)) {
+    let key = (REPUTATION, address.clone());
+    let mut record = get_record(env, address);
+    f(&mut record);
+    env.storage().persistent().set(&key, &record);
+    ttl::extend(env, &key, DataClass::Record);
+}
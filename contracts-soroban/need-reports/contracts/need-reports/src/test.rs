@@ -1,7 +1,29 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env, Address, String, Vec};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token, Bytes, Env, Address, BytesN, String, Symbol, Vec};
+
+fn register_categories(env: &Env, client: &NeedReportsContractClient, admin: &Address) {
+    client.add_category(admin, &String::from_str(env, "Medical"), &String::from_str(env, "Medical"));
+    client.add_category(admin, &String::from_str(env, "Food"), &String::from_str(env, "Food"));
+    client.add_category(admin, &String::from_str(env, "Shelter"), &String::from_str(env, "Shelter"));
+}
+
+fn setup_token(env: &Env, client: &NeedReportsContractClient, admin: &Address) -> Address {
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    client.set_donation_token(admin, &token);
+    token
+}
+
+fn geo(env: &Env, country_code: &str, region: &str, geohash: Option<&str>) -> GeoLocation {
+    GeoLocation {
+        country_code: String::from_str(env, country_code),
+        region: String::from_str(env, region),
+        latitude_e6: None,
+        longitude_e6: None,
+        geohash: geohash.map(|g| String::from_str(env, g)),
+    }
+}
 
 #[test]
 fn test_initialize_contract() {
@@ -35,15 +57,16 @@ fn test_create_report() {
 
     let admin = Address::generate(&env);
     let mut admins = Vec::new(&env);
-    admins.push_back(admin);
+    admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator = Address::generate(&env);
     let title = String::from_str(&env, "Emergency Medical Aid");
     let description = String::from_str(&env, "Need medical supplies for earthquake victims");
     let location = String::from_str(&env, "City Center");
     let category = String::from_str(&env, "Medical");
-    let amount_needed = 5000u64;
+    let amount_needed = 5000i128;
     let mut image_urls = Vec::new(&env);
     image_urls.push_back(String::from_str(&env, "https://firebase.com/image1.jpg"));
 
@@ -52,6 +75,7 @@ fn test_create_report() {
         &title,
         &description,
         &location,
+        &geo(&env, "CO", "Antioquia", None),
         &category,
         &amount_needed,
         &image_urls,
@@ -78,15 +102,16 @@ fn test_update_report() {
 
     let admin = Address::generate(&env);
     let mut admins = Vec::new(&env);
-    admins.push_back(admin);
+    admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator = Address::generate(&env);
     let title = String::from_str(&env, "Emergency Medical Aid");
     let description = String::from_str(&env, "Need medical supplies");
     let location = String::from_str(&env, "City Center");
     let category = String::from_str(&env, "Medical");
-    let amount_needed = 5000u64;
+    let amount_needed = 5000i128;
     let mut image_urls = Vec::new(&env);
     image_urls.push_back(String::from_str(&env, "https://firebase.com/image1.jpg"));
 
@@ -95,6 +120,7 @@ fn test_update_report() {
         &title,
         &description,
         &location,
+        &geo(&env, "CO", "Antioquia", None),
         &category,
         &amount_needed,
         &image_urls,
@@ -113,6 +139,7 @@ fn test_update_report() {
         &None,
         &None,
         &None,
+        &None,
         &reason,
     );
 
@@ -139,13 +166,14 @@ fn test_update_status_admin_only() {
     let mut admins = Vec::new(&env);
     admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator = Address::generate(&env);
     let title = String::from_str(&env, "Emergency Medical Aid");
     let description = String::from_str(&env, "Need medical supplies");
     let location = String::from_str(&env, "City Center");
     let category = String::from_str(&env, "Medical");
-    let amount_needed = 5000u64;
+    let amount_needed = 5000i128;
     let image_urls = Vec::new(&env);
 
     let report_id = client.create_report(
@@ -153,6 +181,7 @@ fn test_update_status_admin_only() {
         &title,
         &description,
         &location,
+        &geo(&env, "CO", "Antioquia", None),
         &category,
         &amount_needed,
         &image_urls,
@@ -181,8 +210,9 @@ fn test_get_user_reports() {
 
     let admin = Address::generate(&env);
     let mut admins = Vec::new(&env);
-    admins.push_back(admin);
+    admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator1 = Address::generate(&env);
     let creator2 = Address::generate(&env);
@@ -195,8 +225,9 @@ fn test_get_user_reports() {
         &String::from_str(&env, "Report 1 by User 1"),
         &String::from_str(&env, "Description 1"),
         &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Medical"),
-        &1000u64,
+        &1000i128,
         &image_urls,
     );
     
@@ -205,8 +236,9 @@ fn test_get_user_reports() {
         &String::from_str(&env, "Report 2 by User 1"),
         &String::from_str(&env, "Description 2"),
         &String::from_str(&env, "Location 2"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Food"),
-        &2000u64,
+        &2000i128,
         &image_urls,
     );
     
@@ -215,8 +247,9 @@ fn test_get_user_reports() {
         &String::from_str(&env, "Report 1 by User 2"),
         &String::from_str(&env, "Description 3"),
         &String::from_str(&env, "Location 3"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Shelter"),
-        &3000u64,
+        &3000i128,
         &image_urls,
     );
 
@@ -241,6 +274,7 @@ fn test_get_reports_by_status() {
     let mut admins = Vec::new(&env);
     admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator = Address::generate(&env);
     let image_urls = Vec::new(&env);
@@ -251,8 +285,9 @@ fn test_get_reports_by_status() {
         &String::from_str(&env, "Report 1"),
         &String::from_str(&env, "Description 1"),
         &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Medical"),
-        &1000u64,
+        &1000i128,
         &image_urls,
     );
     
@@ -261,8 +296,9 @@ fn test_get_reports_by_status() {
         &String::from_str(&env, "Report 2"),
         &String::from_str(&env, "Description 2"),
         &String::from_str(&env, "Location 2"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Food"),
-        &2000u64,
+        &2000i128,
         &image_urls,
     );
 
@@ -297,6 +333,7 @@ fn test_platform_stats() {
     let mut admins = Vec::new(&env);
     admins.push_back(admin.clone());
     client.initialize(&admins);
+    register_categories(&env, &client, &admin);
 
     let creator = Address::generate(&env);
     let image_urls = Vec::new(&env);
@@ -307,8 +344,9 @@ fn test_platform_stats() {
         &String::from_str(&env, "Report 1"),
         &String::from_str(&env, "Description 1"),
         &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Medical"),
-        &1000u64,
+        &1000i128,
         &image_urls,
     );
     
@@ -317,8 +355,9 @@ fn test_platform_stats() {
         &String::from_str(&env, "Report 2"),
         &String::from_str(&env, "Description 2"),
         &String::from_str(&env, "Location 2"),
+        &geo(&env, "CO", "Antioquia", None),
         &String::from_str(&env, "Food"),
-        &2000u64,
+        &2000i128,
         &image_urls,
     );
 
@@ -328,4 +367,2070 @@ fn test_platform_stats() {
     assert_eq!(stats.verified_reports, 0);
     assert_eq!(stats.total_amount_needed, 3000);
     assert_eq!(stats.total_amount_raised, 0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_category_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+
+    let food = String::from_str(&env, "food");
+    assert!(client.add_category(&admin, &food, &String::from_str(&env, "Food")));
+    assert!(client.add_category(&admin, &String::from_str(&env, "medical"), &String::from_str(&env, "Medical")));
+    
+    // Duplicate IDs are rejected
+    assert!(!client.add_category(&admin, &food, &String::from_str(&env, "Alimentos")));
+
+    assert!(client.deprecate_category(&admin, &food));
+    assert!(!client.deprecate_category(&admin, &food));
+
+    let categories = client.list_categories();
+    assert_eq!(categories.len(), 2);
+    assert_eq!(categories.get(0).unwrap().id, food);
+    assert!(categories.get(0).unwrap().deprecated);
+    assert!(!categories.get(1).unwrap().deprecated);
+}
+
+#[test]
+#[should_panic(expected = "Invalid category")]
+fn test_create_report_rejects_unregistered_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "alimentos"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+fn test_stats_per_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let image_urls = Vec::new(&env);
+    
+    client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &image_urls,
+    );
+    
+    client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 2"),
+        &String::from_str(&env, "Description 2"),
+        &String::from_str(&env, "Location 2"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &2500i128,
+        &image_urls,
+    );
+
+    let stats = client.get_stats();
+    assert_eq!(stats.category_stats.len(), 3);
+    
+    let medical = stats.category_stats.get(0).unwrap();
+    assert_eq!(medical.category, String::from_str(&env, "Medical"));
+    assert_eq!(medical.report_count, 0);
+    
+    let food = stats.category_stats.get(1).unwrap();
+    assert_eq!(food.category, String::from_str(&env, "Food"));
+    assert_eq!(food.report_count, 2);
+    assert_eq!(food.amount_needed, 3500);
+    assert_eq!(food.amount_raised, 0);
+}
+
+#[test]
+fn test_region_and_geohash_queries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let image_urls = Vec::new(&env);
+    
+    let medellin_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Medellin"),
+        &geo(&env, "CO", "Antioquia", Some("d3q6hm8")),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &image_urls,
+    );
+    
+    let bogota_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 2"),
+        &String::from_str(&env, "Description 2"),
+        &String::from_str(&env, "Bogota"),
+        &geo(&env, "CO", "Cundinamarca", Some("d2g6dg")),
+        &String::from_str(&env, "Food"),
+        &2000i128,
+        &image_urls,
+    );
+
+    let antioquia = client.get_reports_by_region(
+        &String::from_str(&env, "CO"),
+        &Some(String::from_str(&env, "Antioquia")),
+    );
+    assert_eq!(antioquia.len(), 1);
+    assert_eq!(antioquia.get(0).unwrap().id, medellin_id);
+    
+    let colombia = client.get_reports_by_region(&String::from_str(&env, "CO"), &None);
+    assert_eq!(colombia.len(), 2);
+
+    // Shared prefix matches both, longer prefixes narrow down
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d")).len(), 2);
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d3q")).len(), 1);
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d3q6hm8")).len(), 1);
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d3q6hm9")).len(), 0);
+
+    // Moving a report updates the indexes
+    client.update_report(
+        &bogota_id,
+        &creator,
+        &None,
+        &None,
+        &None,
+        &Some(geo(&env, "CO", "Antioquia", Some("d3q6hk"))),
+        &None,
+        &None,
+        &None,
+        &String::from_str(&env, "Relocated"),
+    );
+    
+    let cundinamarca = client.get_reports_by_region(
+        &String::from_str(&env, "CO"),
+        &Some(String::from_str(&env, "Cundinamarca")),
+    );
+    assert_eq!(cundinamarca.len(), 0);
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d3q6h")).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Invalid location")]
+fn test_create_report_rejects_invalid_country_code() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Medellin"),
+        &geo(&env, "Colombia", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+fn test_verifier_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+
+    let verifier = Address::generate(&env);
+    assert!(client.is_verifier(&admin));
+    assert!(!client.is_verifier(&verifier));
+    
+    assert!(client.add_verifier(&admin, &verifier));
+    assert!(!client.add_verifier(&admin, &verifier));
+    assert!(client.is_verifier(&verifier));
+    
+    assert!(client.remove_verifier(&admin, &verifier));
+    assert!(!client.is_verifier(&verifier));
+}
+
+#[test]
+fn test_verifier_attests_only_pending_reports() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+
+    let creator = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for _ in 0..2 {
+        report_ids.push_back(client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &1000i128,
+            &Vec::new(&env),
+        ));
+    }
+    let (rejected, attested) = (report_ids.get(0).unwrap(), report_ids.get(1).unwrap());
+    let verified_status = String::from_str(&env, "verified");
+    let notes = String::from_str(&env, "Checked");
+
+    // A report an admin rejected stays closed for verifiers and admins alike
+    client.update_status(&rejected, &admin, &String::from_str(&env, "rejected"), &notes);
+    assert!(client.try_update_status(&rejected, &verifier, &verified_status, &notes).is_err());
+    assert!(client.try_update_status(&rejected, &admin, &verified_status, &notes).is_err());
+    assert_eq!(client.get_open_report_count(&creator), 1);
+
+    assert!(client.update_status(&attested, &verifier, &verified_status, &notes));
+    assert!(client.try_update_status(&attested, &verifier, &verified_status, &notes).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Report is locked")]
+fn test_update_report_locked_after_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    
+    assert!(!client.is_report_locked(&report_id));
+    client.update_status(
+        &report_id,
+        &admin,
+        &String::from_str(&env, "verified"),
+        &String::from_str(&env, "Verified"),
+    );
+    assert!(client.is_report_locked(&report_id));
+
+    client.update_report(
+        &report_id,
+        &creator,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some(50000i128),
+        &None,
+        &String::from_str(&env, "Need more"),
+    );
+}
+
+#[test]
+fn test_amendment_approval_flow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    client.update_status(
+        &report_id,
+        &admin,
+        &String::from_str(&env, "verified"),
+        &String::from_str(&env, "Verified"),
+    );
+    let log_len = client.get_change_log(&report_id).len();
+
+    let changes = ReportChanges {
+        title: None,
+        description: None,
+        location: None,
+        geo: GeoChange::Unchanged,
+        category: None,
+        amount_needed: Some(1500i128),
+        image_urls: None,
+    };
+    
+    // First proposal is rejected and leaves the report untouched
+    client.propose_amendment(&report_id, &creator, &changes, &String::from_str(&env, "Prices rose"));
+    assert!(client.get_pending_amendment(&report_id).is_some());
+    assert!(client.reject_amendment(&report_id, &verifier, &String::from_str(&env, "No invoice")));
+    assert!(client.get_pending_amendment(&report_id).is_none());
+    assert_eq!(client.get_report(&report_id).unwrap().amount_needed, 1000);
+    assert_eq!(client.get_change_log(&report_id).len(), log_len);
+
+    // Second proposal is approved and applied
+    let amendment_id = client.propose_amendment(&report_id, &creator, &changes, &String::from_str(&env, "Invoice attached"));
+    assert_eq!(amendment_id, 1);
+    client.pause(&admin, &PAUSE_CREATIONS);
+    assert!(client.try_approve_amendment(&report_id, &verifier, &String::from_str(&env, "Invoice checked")).is_err());
+    client.unpause(&admin, &PAUSE_CREATIONS);
+    assert!(client.approve_amendment(&report_id, &verifier, &String::from_str(&env, "Invoice checked")));
+    assert_eq!(client.get_report(&report_id).unwrap().amount_needed, 1500);
+    assert_eq!(client.get_change_log(&report_id).len(), log_len + 1);
+
+    let amendments = client.get_amendments(&report_id);
+    assert_eq!(amendments.len(), 2);
+    assert_eq!(amendments.get(0).unwrap().status, String::from_str(&env, "rejected"));
+    assert_eq!(amendments.get(1).unwrap().status, String::from_str(&env, "approved"));
+    assert_eq!(amendments.get(1).unwrap().reviewed_by, Some(verifier.clone()));
+
+    // Disputed and closed reports can't be amended
+    client.propose_amendment(&report_id, &creator, &changes, &String::from_str(&env, "Prices rose again"));
+    client.flag_report(&report_id, &verifier, &String::from_str(&env, "Inflated need"), &BytesN::from_array(&env, &[1u8; 32]));
+    assert!(client.try_approve_amendment(&report_id, &verifier, &String::from_str(&env, "Invoice checked")).is_err());
+    assert!(client.reject_amendment(&report_id, &verifier, &String::from_str(&env, "Under dispute")));
+    client.resolve_dispute(&report_id, &admin, &true, &false, &String::from_str(&env, "Need was inflated"));
+    assert!(client
+        .try_propose_amendment(&report_id, &creator, &changes, &String::from_str(&env, "Prices rose again"))
+        .is_err());
+}
+
+#[test]
+fn test_identity_levels_and_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let unverified = String::from_str(&env, "unverified");
+    let ngo_verified = String::from_str(&env, "ngo_verified");
+    client.set_level_limits(&admin, &unverified, &1000i128, &1u32);
+    client.set_level_limits(&admin, &ngo_verified, &100000i128, &5u32);
+
+    let creator = Address::generate(&env);
+    assert_eq!(client.get_identity_level(&creator), unverified);
+
+    let create = |amount: i128| {
+        client.try_create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &amount,
+            &Vec::new(&env),
+        )
+    };
+    
+    // Unverified: amount cap and a single open report
+    assert!(create(5000).is_err());
+    let report_id = create(1000).unwrap().unwrap();
+    assert!(create(500).is_err());
+    assert_eq!(client.get_open_report_count(&creator), 1);
+
+    // Closing the report frees the slot
+    client.update_status(
+        &report_id,
+        &admin,
+        &String::from_str(&env, "rejected"),
+        &String::from_str(&env, "Duplicate"),
+    );
+    assert_eq!(client.get_open_report_count(&creator), 0);
+
+    // NGO verification raises the limits
+    let evidence = BytesN::from_array(&env, &[7u8; 32]);
+    client.set_identity_level(&admin, &creator, &ngo_verified, &evidence);
+    assert_eq!(client.get_identity(&creator).unwrap().evidence_hash, evidence);
+    assert!(create(5000).is_ok());
+    assert!(create(5000).is_ok());
+    assert_eq!(client.get_open_report_count(&creator), 2);
+}
+
+#[test]
+fn test_organization_reports_and_payout_routing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+
+    // The donation token can't be swapped out from under escrowed funds
+    let other_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    assert!(client.try_set_donation_token(&admin, &other_token).is_err());
+    assert_eq!(client.get_donation_token(), Some(token.clone()));
+
+    let org_admin = Address::generate(&env);
+    let org_treasury = Address::generate(&env);
+    let org_id = client.register_organization(
+        &admin,
+        &String::from_str(&env, "Cruz Roja Antioquia"),
+        &org_admin,
+        &org_treasury,
+    );
+    
+    // Org admins manage members on their own
+    let caseworker = Address::generate(&env);
+    assert!(client.add_org_member(&org_id, &org_admin, &caseworker));
+    assert!(client.is_org_member(&org_id, &caseworker));
+
+    let beneficiary = Address::generate(&env);
+    let report_id = client.create_report_on_behalf(
+        &caseworker,
+        &org_id,
+        &beneficiary,
+        &String::from_str(&env, "Roof repair"),
+        &String::from_str(&env, "Family without wallet"),
+        &String::from_str(&env, "Rural area"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Shelter"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    
+    let report = client.get_report(&report_id).unwrap();
+    assert_eq!(report.creator, caseworker);
+    assert_eq!(report.beneficiary, beneficiary);
+    assert_eq!(report.organization_id, Some(org_id));
+    assert_eq!(client.get_payout_recipient(&report_id), Some(org_treasury.clone()));
+    // Open report limits follow the beneficiary, not the filing member
+    assert_eq!(client.get_open_report_count(&beneficiary), 1);
+    assert_eq!(client.get_open_report_count(&caseworker), 0);
+
+    client.update_status(
+        &report_id,
+        &admin,
+        &String::from_str(&env, "verified"),
+        &String::from_str(&env, "Verified"),
+    );
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+    assert!(client.donate(&report_id, &donor, &1000i128, &0i128));
+    assert_eq!(client.get_escrow_balance(&report_id), 1000);
+    assert_eq!(client.get_report(&report_id).unwrap().status, String::from_str(&env, "funded"));
+
+    // Org routes the first payout to its treasury, then directly to the beneficiary
+    let token_client = token::Client::new(&env, &token);
+    assert!(client.release_funds(&report_id, &admin, &400i128));
+    assert_eq!(token_client.balance(&org_treasury), 400);
+    
+    client.set_org_payout(&org_id, &org_admin, &org_treasury, &true);
+    assert!(client.release_funds(&report_id, &admin, &600i128));
+    assert_eq!(token_client.balance(&beneficiary), 600);
+    assert_eq!(client.get_escrow_balance(&report_id), 0);
+    assert_eq!(client.get_report(&report_id).unwrap().amount_released, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_create_report_on_behalf_requires_membership() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let org_admin = Address::generate(&env);
+    let org_id = client.register_organization(
+        &admin,
+        &String::from_str(&env, "NGO"),
+        &org_admin,
+        &org_admin,
+    );
+
+    let outsider = Address::generate(&env);
+    client.create_report_on_behalf(
+        &outsider,
+        &org_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "Report"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Shelter"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+fn test_delegated_report_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    env.ledger().set_timestamp(1000);
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+
+    let caseworker = Address::generate(&env);
+    assert!(!client.is_delegate(&report_id, &caseworker, &DELEGATE_UPDATE_REPORT));
+    
+    client.add_delegate(&creator, &Some(report_id), &caseworker, &DELEGATE_UPDATE_REPORT, &2000u64);
+    assert!(client.is_delegate(&report_id, &caseworker, &DELEGATE_UPDATE_REPORT));
+    assert!(!client.is_delegate(&report_id, &caseworker, &DELEGATE_PROPOSE_AMENDMENT));
+
+    let new_title = String::from_str(&env, "Updated by caseworker");
+    let update = |title: &String| {
+        client.try_update_report(
+            &report_id,
+            &caseworker,
+            &Some(title.clone()),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &String::from_str(&env, "Caseworker update"),
+        )
+    };
+    assert!(update(&new_title).is_ok());
+    assert_eq!(client.get_report(&report_id).unwrap().title, new_title);
+
+    // Expired delegations no longer authorize updates
+    env.ledger().set_timestamp(2000);
+    assert!(!client.is_delegate(&report_id, &caseworker, &DELEGATE_UPDATE_REPORT));
+    assert!(update(&String::from_str(&env, "Too late")).is_err());
+
+    // Creator-wide delegation, then revocation; both are logged on the report
+    client.add_delegate(&creator, &None, &caseworker, &DELEGATE_UPDATE_REPORT, &5000u64);
+    assert!(client.is_delegate(&report_id, &caseworker, &DELEGATE_UPDATE_REPORT));
+    assert!(client.remove_delegate(&creator, &None, &caseworker));
+    assert!(!client.is_delegate(&report_id, &caseworker, &DELEGATE_UPDATE_REPORT));
+
+    let delegate_field = String::from_str(&env, "delegate");
+    let delegate_logs = client
+        .get_change_log(&report_id)
+        .iter()
+        .filter(|entry| entry.field_changed == delegate_field)
+        .count();
+    assert_eq!(delegate_logs, 3);
+}
+
+#[test]
+fn test_dispute_freezes_and_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    let verified = String::from_str(&env, "verified");
+    client.update_status(&report_id, &admin, &verified, &String::from_str(&env, "Verified"));
+
+    let donor1 = Address::generate(&env);
+    let donor2 = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor1, &300);
+    token::StellarAssetClient::new(&env, &token).mint(&donor2, &100);
+    client.donate(&report_id, &donor1, &300i128, &0i128);
+    client.donate(&report_id, &donor2, &100i128, &0i128);
+
+    // Only donors and verifiers may flag, and disputes are not opened through update_status
+    let evidence = BytesN::from_array(&env, &[1u8; 32]);
+    let stranger = Address::generate(&env);
+    assert!(client.try_flag_report(&report_id, &stranger, &String::from_str(&env, "Spam"), &evidence).is_err());
+    assert!(client.try_update_status(&report_id, &admin, &String::from_str(&env, "disputed"), &String::from_str(&env, "Suspicious")).is_err());
+
+    // Dismissed dispute restores the previous status
+    client.flag_report(&report_id, &donor1, &String::from_str(&env, "Photos look reused"), &evidence);
+    assert_eq!(client.get_report(&report_id).unwrap().status, String::from_str(&env, "disputed"));
+    assert!(client.try_donate(&report_id, &donor2, &10i128, &0i128).is_err());
+    assert!(client.try_release_funds(&report_id, &admin, &100i128).is_err());
+    
+    // The report stays frozen while disputed and can only leave through resolve_dispute
+    assert!(client.is_report_locked(&report_id));
+    assert!(client.try_update_report(
+        &report_id,
+        &creator,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+        &Some(999999i128),
+        &None,
+        &String::from_str(&env, "Need more"),
+    ).is_err());
+    assert!(client.try_update_status(&report_id, &admin, &verified, &String::from_str(&env, "Cleared")).is_err());
+    
+    client.resolve_dispute(&report_id, &admin, &false, &false, &String::from_str(&env, "Photos verified"));
+    assert_eq!(client.get_report(&report_id).unwrap().status, verified);
+    assert_eq!(client.get_disputes(&report_id).get(0).unwrap().status, String::from_str(&env, "dismissed"));
+
+    // Upheld dispute rejects the report and refunds the remaining escrow pro rata
+    client.release_funds(&report_id, &admin, &200i128);
+    client.flag_report(&report_id, &donor2, &String::from_str(&env, "Beneficiary unknown"), &evidence);
+    client.resolve_dispute(&report_id, &admin, &true, &true, &String::from_str(&env, "Fraud confirmed"));
+    
+    assert_eq!(client.get_report(&report_id).unwrap().status, String::from_str(&env, "rejected"));
+    assert_eq!(client.get_escrow_balance(&report_id), 0);
+    assert_eq!(token_client.balance(&donor1), 150);
+    assert_eq!(token_client.balance(&donor2), 50);
+    assert_eq!(client.get_open_report_count(&creator), 0);
+}
+
+#[test]
+fn test_release_requires_live_report_and_rejected_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    assert!(client.try_release_funds(&report_id, &admin, &1i128).is_err());
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &400);
+    client.donate(&report_id, &donor, &400i128, &0i128);
+    client.release_funds(&report_id, &admin, &100i128);
+
+    // An upheld dispute without refund freezes payouts and leaves escrow for the donors
+    let evidence = BytesN::from_array(&env, &[2u8; 32]);
+    client.flag_report(&report_id, &donor, &String::from_str(&env, "Fake"), &evidence);
+    client.resolve_dispute(&report_id, &admin, &true, &false, &String::from_str(&env, "Fraud confirmed"));
+    assert!(client.try_release_funds(&report_id, &admin, &300i128).is_err());
+
+    let stranger = Address::generate(&env);
+    assert!(client.try_refund_rejected(&report_id, &stranger).is_err());
+    assert!(client.refund_rejected(&report_id, &donor));
+    assert_eq!(token_client.balance(&donor), 300);
+    assert_eq!(client.get_escrow_balance(&report_id), 0);
+    assert!(!client.refund_rejected(&report_id, &admin));
+}
+
+#[test]
+fn test_completion_requires_verifier_confirmation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    
+    // Admins can no longer complete a report directly
+    assert!(client
+        .try_update_status(&report_id, &admin, &String::from_str(&env, "completed"), &String::from_str(&env, "Done"))
+        .is_err());
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+    client.donate(&report_id, &donor, &1000i128, &0i128);
+    client.release_funds(&report_id, &admin, &700i128);
+
+    let mut evidence = Vec::new(&env);
+    evidence.push_back(String::from_str(&env, "https://firebase.com/delivery.jpg"));
+    let mut breakdown = Vec::new(&env);
+    breakdown.push_back(SpendItem {
+        description: String::from_str(&env, "Rice and beans"),
+        amount: 700,
+        receipt_hashes: Vec::from_array(&env, [BytesN::from_array(&env, &[2u8; 32])]),
+    });
+    
+    // Nothing may be left in escrow, and line items must cover the full released amount
+    assert!(client.try_submit_completion(&report_id, &creator, &evidence, &breakdown).is_err());
+    client.release_funds(&report_id, &admin, &300i128);
+    assert!(client.try_submit_completion(&report_id, &creator, &evidence, &breakdown).is_err());
+    
+    breakdown.push_back(SpendItem {
+        description: String::from_str(&env, "Transport"),
+        amount: 300,
+        receipt_hashes: Vec::new(&env),
+    });
+    assert!(client.submit_completion(&report_id, &creator, &evidence, &breakdown));
+    assert!(client.reject_completion(&report_id, &verifier, &String::from_str(&env, "Receipt missing")));
+    assert_eq!(client.get_report(&report_id).unwrap().status, String::from_str(&env, "funded"));
+
+    // Funds that arrive during review can't be released until the completion is sent back
+    assert!(client.submit_completion(&report_id, &creator, &evidence, &breakdown));
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    client.donate(&report_id, &donor, &100i128, &0i128);
+    assert!(client.try_release_funds(&report_id, &admin, &100i128).is_err());
+    assert!(client.try_confirm_completion(&report_id, &verifier, &String::from_str(&env, "Delivery confirmed")).is_err());
+    assert!(client.reject_completion(&report_id, &verifier, &String::from_str(&env, "New funds to account for")));
+    client.release_funds(&report_id, &admin, &100i128);
+    breakdown.push_back(SpendItem {
+        description: String::from_str(&env, "Cooking gas"),
+        amount: 100,
+        receipt_hashes: Vec::new(&env),
+    });
+    
+    // A report disputed during review can't be completed
+    assert!(client.submit_completion(&report_id, &creator, &evidence, &breakdown));
+    client.flag_report(&report_id, &donor, &String::from_str(&env, "Not delivered"), &BytesN::from_array(&env, &[3u8; 32]));
+    assert!(client.try_confirm_completion(&report_id, &verifier, &String::from_str(&env, "Delivery confirmed")).is_err());
+    client.resolve_dispute(&report_id, &admin, &false, &false, &String::from_str(&env, "Delivery checked"));
+    assert!(client.confirm_completion(&report_id, &verifier, &String::from_str(&env, "Delivery confirmed")));
+    assert_eq!(client.get_report(&report_id).unwrap().status, String::from_str(&env, "completed"));
+    assert_eq!(client.get_completion(&report_id).unwrap().status, String::from_str(&env, "confirmed"));
+}
+
+#[test]
+fn test_reputation_tracks_disputes_and_scales_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    client.set_level_limits(&admin, &String::from_str(&env, "unverified"), &1000i128, &5u32);
+
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+    let creator = Address::generate(&env);
+
+    let create = |amount: i128| {
+        client.try_create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &amount,
+            &Vec::new(&env),
+        )
+    };
+    
+    let fresh = client.get_reputation(&creator);
+    assert_eq!(fresh.creator_score, 50);
+    assert_eq!(fresh.verifier_accuracy_bps, 10000);
+
+    let report_id = create(1000).unwrap().unwrap();
+    client.update_status(&report_id, &verifier, &String::from_str(&env, "verified"), &String::from_str(&env, "Checked"));
+    assert_eq!(client.get_report_attester(&report_id), Some(verifier.clone()));
+    assert_eq!(client.get_reputation(&verifier).record.attestations, 1);
+
+    let evidence = BytesN::from_array(&env, &[3u8; 32]);
+    client.flag_report(&report_id, &admin, &String::from_str(&env, "Fake"), &evidence);
+    client.resolve_dispute(&report_id, &admin, &true, &false, &String::from_str(&env, "Fraud confirmed"));
+
+    // Overturned attestation hurts the verifier; rejection and dispute hurt the creator
+    let verifier_rep = client.get_reputation(&verifier);
+    assert_eq!(verifier_rep.record.overturned_attestations, 1);
+    assert_eq!(verifier_rep.verifier_accuracy_bps, 0);
+    
+    let creator_rep = client.get_reputation(&creator);
+    assert_eq!(creator_rep.record.rejected_reports, 1);
+    assert_eq!(creator_rep.record.upheld_disputes, 1);
+    assert_eq!(creator_rep.creator_score, 10);
+
+    // The amount cap shrinks with the score (1000 * 10 / 50)
+    assert!(create(201).is_err());
+    assert!(create(200).is_ok());
+}
+
+#[test]
+fn test_pause_scopes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let create = || {
+        client.try_create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &1000i128,
+            &Vec::new(&env),
+        )
+    };
+    let report_id = create().unwrap().unwrap();
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+
+    // Donations paused: creations still work, reads are unaffected
+    assert_eq!(client.pause(&admin, &PAUSE_DONATIONS), PAUSE_DONATIONS);
+    assert!(client.try_donate(&report_id, &donor, &100i128, &0i128).is_err());
+    assert!(create().is_ok());
+    assert!(client.get_report(&report_id).is_some());
+
+    // Full stop, then lift everything
+    assert_eq!(client.pause(&admin, &PAUSE_ALL), PAUSE_ALL);
+    assert!(create().is_err());
+    assert!(client.try_release_funds(&report_id, &admin, &1i128).is_err());
+    assert_eq!(client.get_stats().total_reports, 2);
+    
+    assert_eq!(client.unpause(&admin, &PAUSE_ALL), 0);
+    assert!(client.donate(&report_id, &donor, &100i128, &0i128));
+}
+
+#[test]
+fn test_platform_fee_and_tips() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+
+    let treasury = Address::generate(&env);
+    assert!(client.try_set_fee_config(&admin, &(MAX_FEE_BPS + 1), &treasury).is_err());
+    client.set_fee_config(&admin, &250u32, &treasury);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report 1"),
+        &String::from_str(&env, "Description 1"),
+        &String::from_str(&env, "Location 1"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &5000i128,
+        &Vec::new(&env),
+    );
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &2000);
+    client.donate(&report_id, &donor, &1000i128, &50i128);
+    client.donate(&report_id, &donor, &400i128, &0i128);
+
+    // 2.5% of each donation plus the tip goes to the treasury
+    assert_eq!(token_client.balance(&treasury), 25 + 50 + 10);
+    assert_eq!(client.get_escrow_balance(&report_id), 975 + 390);
+    assert_eq!(client.get_report(&report_id).unwrap().amount_raised, 1365);
+
+    let donation = client.get_donations(&report_id).get(0).unwrap();
+    assert_eq!(donation.amount, 975);
+    assert_eq!(donation.fee, 25);
+    assert_eq!(donation.tip, 50);
+
+    let stats = client.get_stats();
+    assert_eq!(stats.total_fees_collected, 35);
+    assert_eq!(stats.total_tips_collected, 50);
+}
+
+#[test]
+fn test_matching_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+    env.ledger().set_timestamp(100);
+
+    let sponsor = Address::generate(&env);
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&sponsor, &1000);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+
+    // 1:1 match for food in Antioquia, at most 300 per report
+    let pool_id = client.create_matching_pool(
+        &sponsor,
+        &1000i128,
+        &10000u32,
+        &Some(String::from_str(&env, "Food")),
+        &Some(String::from_str(&env, "CO")),
+        &Some(String::from_str(&env, "Antioquia")),
+        &300i128,
+        &false,
+        &1000u64,
+    );
+
+    let creator = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for category in ["Food", "Shelter"] {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, category),
+            &5000i128,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+        report_ids.push_back(report_id);
+    }
+    let food_id = report_ids.get(0).unwrap();
+    let shelter_id = report_ids.get(1).unwrap();
+
+    client.donate(&food_id, &donor, &200i128, &0i128);
+    client.donate(&food_id, &donor, &200i128, &0i128);
+    client.donate(&shelter_id, &donor, &200i128, &0i128);
+
+    // Second donation only gets the remaining 100 under the cap; shelter doesn't qualify
+    assert_eq!(client.get_pool_matched(&pool_id, &food_id), 300);
+    assert_eq!(client.get_report(&food_id).unwrap().amount_raised, 700);
+    assert_eq!(client.get_report(&shelter_id).unwrap().amount_raised, 200);
+    assert_eq!(client.get_matching_pool(&pool_id).unwrap().balance, 700);
+
+    // Sponsor recovers the unused balance only after expiry
+    assert!(client.try_withdraw_pool_balance(&pool_id, &sponsor).is_err());
+    env.ledger().set_timestamp(1000);
+    assert_eq!(client.withdraw_pool_balance(&pool_id, &sponsor), 700);
+    assert_eq!(token_client.balance(&sponsor), 700);
+    assert_eq!(client.get_active_pools().len(), 0);
+}
+
+#[test]
+fn test_isqrt() {
+    assert_eq!(qf::isqrt(0), 0);
+    assert_eq!(qf::isqrt(1), 1);
+    assert_eq!(qf::isqrt(99), 9);
+    assert_eq!(qf::isqrt(100), 10);
+    assert_eq!(qf::isqrt(u64::MAX as u128), 4294967295);
+}
+
+#[test]
+fn test_quadratic_funding_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let minter = token::StellarAssetClient::new(&env, &token);
+    env.ledger().set_timestamp(100);
+
+    minter.mint(&admin, &1000);
+    let round_id = client.create_qf_round(&admin, &String::from_str(&env, "Earthquake"), &1000i128, &100u64, &500u64);
+
+    let creator = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for _ in 0..2 {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &100000i128,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+        assert!(client.register_for_round(&round_id, &creator, &report_id));
+        report_ids.push_back(report_id);
+    }
+    let broad_id = report_ids.get(0).unwrap();
+    let whale_id = report_ids.get(1).unwrap();
+
+    // Four donors giving 100 each vs. one donor giving 400
+    for _ in 0..4 {
+        let donor = Address::generate(&env);
+        minter.mint(&donor, &100);
+        client.donate(&broad_id, &donor, &100i128, &0i128);
+    }
+    let whale = Address::generate(&env);
+    minter.mint(&whale, &400);
+    client.donate(&whale_id, &whale, &400i128, &0i128);
+
+    assert!(client.try_finalize_qf_round(&round_id, &admin).is_err());
+    env.ledger().set_timestamp(500);
+    let allocations = client.finalize_qf_round(&round_id, &admin);
+
+    // (4 * 10)^2 - 400 = 1200 vs (20)^2 - 400 = 0: broad support takes the whole pool
+    let broad = allocations.get(0).unwrap();
+    assert_eq!(broad.unique_donors, 4);
+    assert_eq!(broad.total_contributed, 400);
+    assert_eq!(broad.matched, 1000);
+    assert_eq!(allocations.get(1).unwrap().matched, 0);
+    
+    assert_eq!(client.get_report(&broad_id).unwrap().amount_raised, 1400);
+    assert_eq!(client.get_escrow_balance(&whale_id), 400);
+    assert!(client.get_qf_round(&round_id).unwrap().finalized);
+    assert_eq!(client.get_qf_results(&round_id).len(), 2);
+}
+
+#[test]
+fn test_recurring_donation_subscription() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    env.ledger().set_timestamp(1000);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Monthly meals"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &5000i128,
+        &Vec::new(&env),
+    );
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &1000);
+    token::Client::new(&env, &token).approve(&donor, &contract_id, &1000, &1000u32);
+
+    let month = 30 * 24 * 60 * 60u64;
+    let subscription_id = client.create_subscription(
+        &donor,
+        &SubscriptionTarget::Category(String::from_str(&env, "Food")),
+        &100i128,
+        &month,
+    );
+
+    // Nothing to pay into until a food report is verified
+    assert_eq!(client.process_subscription(&subscription_id), None);
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    
+    assert_eq!(client.process_subscription(&subscription_id), Some(report_id));
+    assert!(client.try_process_subscription(&subscription_id).is_err());
+    
+    env.ledger().set_timestamp(1000 + month);
+    assert_eq!(client.process_subscription(&subscription_id), Some(report_id));
+
+    let subscription = client.get_subscription(&subscription_id).unwrap();
+    assert_eq!(subscription.payments_made, 2);
+    assert_eq!(subscription.next_due, 1000 + 2 * month);
+    assert_eq!(client.get_donations(&report_id).len(), 2);
+    assert_eq!(client.get_escrow_balance(&report_id), 200);
+
+    assert!(client.cancel_subscription(&subscription_id, &donor));
+    env.ledger().set_timestamp(1000 + 2 * month);
+    assert!(client.try_process_subscription(&subscription_id).is_err());
+    assert_eq!(client.get_donor_subscriptions(&donor).len(), 1);
+}
+
+#[test]
+fn test_campaign_split_and_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    env.ledger().set_timestamp(100);
+
+    let campaign_id = client.create_campaign(
+        &admin,
+        &String::from_str(&env, "Earthquake response"),
+        &100u64,
+        &10000u64,
+        &String::from_str(&env, "CO"),
+        &String::from_str(&env, "Antioquia"),
+        &String::from_str(&env, "Shelter"),
+        &SplitRule::ProportionalToRemaining,
+    );
+
+    let creator = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for amount_needed in [1000i128, 3000i128] {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Shelter"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Shelter"),
+            &amount_needed,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+        assert!(client.join_campaign(&campaign_id, &creator, &report_id));
+        report_ids.push_back(report_id);
+    }
+    assert!(!client.join_campaign(&campaign_id, &creator, &report_ids.get(0).unwrap()));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &800);
+    client.donate_to_campaign(&campaign_id, &donor, &800i128, &0i128);
+
+    // Remaining need is 1000 vs 3000, so the split is 200 / 600
+    assert_eq!(client.get_report(&report_ids.get(0).unwrap()).unwrap().amount_raised, 200);
+    assert_eq!(client.get_report(&report_ids.get(1).unwrap()).unwrap().amount_raised, 600);
+    assert_eq!(client.get_report(&report_ids.get(0).unwrap()).unwrap().campaign_id, Some(campaign_id));
+
+    let stats = client.get_campaign_stats(&campaign_id);
+    assert_eq!(stats.total_reports, 2);
+    assert_eq!(stats.total_amount_needed, 4000);
+    assert_eq!(stats.total_amount_raised, 800);
+    assert_eq!(stats.total_donated, 800);
+}
+
+#[test]
+fn test_overfunding_policies() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+
+    let creator = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for _ in 0..3 {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Food"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &1000i128,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+        report_ids.push_back(report_id);
+    }
+    let (rejecting, refunding, redirecting) = (report_ids.get(0).unwrap(), report_ids.get(1).unwrap(), report_ids.get(2).unwrap());
+    assert_eq!(client.get_overfunding_policy(&rejecting), OverfundingPolicy::Accept);
+    client.set_overfunding_policy(&rejecting, &creator, &OverfundingPolicy::Reject);
+    client.set_overfunding_policy(&refunding, &creator, &OverfundingPolicy::Refund);
+    client.set_overfunding_policy(&redirecting, &admin, &OverfundingPolicy::RedirectToGeneralFund);
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &5000);
+
+    assert!(client.try_donate(&rejecting, &donor, &1500i128, &0i128).is_err());
+    assert!(client.try_update_amount_raised(&rejecting, &1500i128, &admin).is_err());
+
+    client.donate(&refunding, &donor, &1500i128, &0i128);
+    assert_eq!(client.get_report(&refunding).unwrap().amount_raised, 1000);
+    assert_eq!(client.get_escrow_balance(&refunding), 1000);
+    assert_eq!(token_client.balance(&donor), 4000);
+
+    client.donate(&redirecting, &donor, &1200i128, &0i128);
+    assert_eq!(client.get_report(&redirecting).unwrap().amount_raised, 1000);
+    assert_eq!(client.get_general_fund(), 200);
+
+    client.allocate_general_fund(&admin, &rejecting, &200i128);
+    assert_eq!(client.get_general_fund(), 0);
+    assert_eq!(client.get_report(&rejecting).unwrap().amount_raised, 200);
+
+    // The platform fee is only charged on the accepted part of a donation
+    let treasury = Address::generate(&env);
+    client.set_fee_config(&admin, &500u32, &treasury);
+    client.donate(&refunding, &donor, &1000i128, &0i128);
+    assert_eq!(token_client.balance(&donor), 2800);
+    assert_eq!(token_client.balance(&treasury), 0);
+
+    // A fully refunded donation books the tip but gives the donor no standing to flag the report
+    let refunded_donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&refunded_donor, &110);
+    client.donate(&refunding, &refunded_donor, &100i128, &10i128);
+    assert_eq!(token_client.balance(&refunded_donor), 100);
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert!(client.get_donations(&refunding).iter().all(|donation| donation.donor != refunded_donor));
+    assert!(client
+        .try_flag_report(&refunding, &refunded_donor, &String::from_str(&env, "Spam"), &BytesN::from_array(&env, &[1u8; 32]))
+        .is_err());
+
+    // Campaign shares beyond a rejecting member's need are refunded instead of reverting the split
+    let campaign_id = client.create_campaign(
+        &admin,
+        &String::from_str(&env, "Food drive"),
+        &0u64,
+        &10000u64,
+        &String::from_str(&env, "CO"),
+        &String::from_str(&env, "Antioquia"),
+        &String::from_str(&env, "Food"),
+        &SplitRule::Equal,
+    );
+    let open_report = client.create_report(
+        &creator,
+        &String::from_str(&env, "Food"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    client.update_status(&open_report, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    client.join_campaign(&campaign_id, &creator, &rejecting);
+    client.join_campaign(&campaign_id, &creator, &open_report);
+
+    // Each share is 1000; the rejecting member takes 842 gross (800 after the 5% fee) and refunds 158
+    client.donate_to_campaign(&campaign_id, &donor, &2000i128, &0i128);
+    assert_eq!(client.get_report(&rejecting).unwrap().amount_raised, 1000);
+    assert_eq!(client.get_report(&open_report).unwrap().amount_raised, 950);
+    assert_eq!(token_client.balance(&donor), 958);
+    assert_eq!(token_client.balance(&treasury), 102);
+
+    // A fully funded rejecting member drops out of the split
+    client.donate_to_campaign(&campaign_id, &donor, &40i128, &0i128);
+    assert_eq!(client.get_report(&rejecting).unwrap().amount_raised, 1000);
+    assert_eq!(client.get_report(&open_report).unwrap().amount_raised, 988);
+
+    // Surplus redirected to the campaign is capped at each member's remaining need
+    let small_report = client.create_report(
+        &creator,
+        &String::from_str(&env, "Food"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &100i128,
+        &Vec::new(&env),
+    );
+    client.update_status(&small_report, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    client.join_campaign(&campaign_id, &creator, &small_report);
+    client.set_overfunding_policy(&small_report, &creator, &OverfundingPolicy::RedirectToCampaign);
+
+    // 105 gross covers the 100 needed; of the 495 surplus the open report takes its last 12
+    client.donate(&small_report, &donor, &600i128, &0i128);
+    assert_eq!(client.get_report(&small_report).unwrap().amount_raised, 100);
+    assert_eq!(client.get_report(&open_report).unwrap().amount_raised, 1000);
+    assert_eq!(client.get_general_fund(), 483);
+}
+
+#[test]
+fn test_amounts_checked_with_typed_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let create = |amount: i128| {
+        client.try_create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &amount,
+            &Vec::new(&env),
+        )
+    };
+
+    let negative = soroban_sdk::Error::from_contract_error(AmountError::Negative as u32);
+    assert_eq!(create(-1).err(), Some(Ok(negative)));
+
+    // Reports default to the Stellar asset decimals until a token is configured
+    let report_id = create(i128::MAX).unwrap().unwrap();
+    assert_eq!(client.get_report(&report_id).unwrap().decimals, 7);
+    assert_eq!(client.get_stats().total_amount_needed, i128::MAX);
+
+    // A further report pushes the platform total past i128::MAX
+    create(1).unwrap().unwrap();
+    let overflow = soroban_sdk::Error::from_contract_error(AmountError::Overflow as u32);
+    assert_eq!(client.try_get_stats().err(), Some(Ok(overflow)));
+}
+
+#[test]
+fn test_ttl_policy_and_keeper_bumps() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    assert!(client.try_set_ttl_policy(&admin, &DataClass::Report, &200u32, &100u32).is_err());
+    client.set_ttl_policy(&admin, &DataClass::Report, &500u32, &1000u32);
+    assert_eq!(client.get_ttl_policy(&DataClass::Report), TtlPolicy { threshold: 500, extend_to: 1000 });
+
+    env.ledger().set_sequence_number(100);
+    let creator = Address::generate(&env);
+    for _ in 0..2 {
+        client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &1000i128,
+            &Vec::new(&env),
+        );
+    }
+    assert_eq!(client.get_expiring_reports(&500u32, &1u64, &10u64).len(), 0);
+
+    // Past the threshold both reports show up as expiring
+    env.ledger().set_sequence_number(700);
+    let expiring = client.get_expiring_reports(&500u32, &1u64, &10u64);
+    assert_eq!(expiring.len(), 2);
+    assert_eq!(client.get_expiring_reports(&500u32, &1u64, &u64::MAX).len(), 2);
+    assert_eq!(expiring.get(0).unwrap(), ReportExpiry { report_id: 1, live_until_ledger: 1100 });
+
+    // A keeper bump pushes report 1 out again; unknown IDs are skipped
+    assert!(client.bump_report(&1u64));
+    assert!(!client.bump_report(&99u64));
+    let expiring = client.get_expiring_reports(&500u32, &1u64, &10u64);
+    assert_eq!(expiring.len(), 1);
+    assert_eq!(expiring.get(0).unwrap().report_id, 2);
+
+    assert_eq!(client.bump_range(&1u64, &5u64), 2);
+    assert_eq!(client.get_expiring_reports(&500u32, &1u64, &10u64).len(), 0);
+    assert!(client.try_bump_range(&1u64, &(MAX_BUMP_RANGE + 1)).is_err());
+}
+
+#[test]
+fn test_archive_closed_report() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let report_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", Some("d3gq")),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    assert!(client.try_archive_report(&report_id, &admin).is_err());
+
+    client.update_status(&report_id, &admin, &String::from_str(&env, "rejected"), &String::from_str(&env, "Duplicate"));
+    let log_entries = client.get_change_log(&report_id).len();
+    assert!(client.archive_report(&report_id, &admin));
+
+    let archived = client.get_archived_report(&report_id).unwrap();
+    assert_eq!(archived.creator, creator);
+    assert_eq!(archived.final_status, String::from_str(&env, "rejected"));
+    assert_eq!(archived.log_entries, log_entries);
+    assert_eq!(archived.amount_needed, 1000);
+
+    // The full report and its index entries are gone, but totals still include it
+    assert!(client.get_report(&report_id).is_none());
+    assert_eq!(client.get_change_log(&report_id).len(), 0);
+    assert_eq!(client.get_reports_by_region(&String::from_str(&env, "CO"), &None).len(), 0);
+    assert_eq!(client.get_reports_near(&String::from_str(&env, "d3g")).len(), 0);
+    assert_eq!(client.get_stats().total_amount_needed, 1000);
+    assert!(!client.archive_report(&report_id, &admin));
+}
+
+#[test]
+fn test_cancel_report_refunds_donors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    let token_client = token::Client::new(&env, &token);
+
+    let creator = Address::generate(&env);
+    let create = || {
+        client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &1000i128,
+            &Vec::new(&env),
+        )
+    };
+    let report_id = create();
+    client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &500);
+    client.donate(&report_id, &donor, &500i128, &0i128);
+    assert_eq!(client.get_open_report_count(&creator), 1);
+
+    let stranger = Address::generate(&env);
+    let reason = String::from_str(&env, "Need met by family");
+    assert!(client.try_cancel_report(&report_id, &stranger, &reason).is_err());
+    assert!(client.try_update_status(&report_id, &admin, &String::from_str(&env, "cancelled"), &reason).is_err());
+
+    // Refunds are payouts, so they wait while payouts are paused
+    client.pause(&admin, &PAUSE_PAYOUTS);
+    assert!(client.try_cancel_report(&report_id, &creator, &reason).is_err());
+    client.unpause(&admin, &PAUSE_PAYOUTS);
+
+    assert!(client.cancel_report(&report_id, &creator, &reason));
+    let report = client.get_report(&report_id).unwrap();
+    assert_eq!(report.status, String::from_str(&env, "cancelled"));
+    assert_eq!(client.get_escrow_balance(&report_id), 0);
+    assert_eq!(token_client.balance(&donor), 500);
+    assert_eq!(client.get_open_report_count(&creator), 0);
+    let log = client.get_change_log(&report_id);
+    assert!(log.iter().any(|entry| entry.new_value == String::from_str(&env, "cancelled") && entry.reason == reason));
+    assert!(client.try_cancel_report(&report_id, &creator, &reason).is_err());
+
+    // Once funds are released the report can no longer be withdrawn
+    let released_id = create();
+    client.update_status(&released_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &100);
+    client.donate(&released_id, &donor, &100i128, &0i128);
+    client.release_funds(&released_id, &admin, &50i128);
+    assert!(client.try_cancel_report(&released_id, &creator, &reason).is_err());
+
+    // The beneficiary of an org-filed report can withdraw it too
+    let org_admin = Address::generate(&env);
+    let org_id = client.register_organization(&admin, &String::from_str(&env, "NGO"), &org_admin, &org_admin);
+    let caseworker = Address::generate(&env);
+    client.add_org_member(&org_id, &org_admin, &caseworker);
+    let beneficiary = Address::generate(&env);
+    let filed_id = client.create_report_on_behalf(
+        &caseworker,
+        &org_id,
+        &beneficiary,
+        &String::from_str(&env, "Report"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &1000i128,
+        &Vec::new(&env),
+    );
+    assert!(client.cancel_report(&filed_id, &beneficiary, &reason));
+    assert_eq!(client.get_report(&filed_id).unwrap().status, String::from_str(&env, "cancelled"));
+}
+
+#[test]
+fn test_tags_and_search() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    let other = Address::generate(&env);
+    let mut report_ids = Vec::new(&env);
+    for (i, (owner, category, amount)) in [
+        (&creator, "Food", 500i128),
+        (&creator, "Medical", 2000i128),
+        (&other, "Food", 1500i128),
+    ].into_iter().enumerate() {
+        env.ledger().set_timestamp(100 * (i as u64 + 1));
+        report_ids.push_back(client.create_report(
+            owner,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, category),
+            &amount,
+            &Vec::new(&env),
+        ));
+    }
+    let (flood, children) = (Symbol::new(&env, "flood"), Symbol::new(&env, "children"));
+    client.set_tags(&report_ids.get(0).unwrap(), &creator, &Vec::from_array(&env, [flood.clone(), children.clone()]));
+    client.set_tags(&report_ids.get(2).unwrap(), &other, &Vec::from_array(&env, [flood.clone()]));
+    assert!(client.try_set_tags(&report_ids.get(1).unwrap(), &other, &Vec::from_array(&env, [flood.clone()])).is_err());
+    assert!(client.try_set_tags(&report_ids.get(1).unwrap(), &creator, &Vec::from_array(&env, [flood.clone(), flood.clone()])).is_err());
+
+    let any = ReportFilter {
+        status: None,
+        category: None,
+        tags: Vec::new(&env),
+        creator: None,
+        min_amount: None,
+        max_amount: None,
+        created_after: None,
+        created_before: None,
+    };
+    let ids = |page: ReportPage| {
+        let mut ids = Vec::new(&env);
+        for report in page.reports.iter() {
+            ids.push_back(report.id);
+        }
+        ids
+    };
+
+    let tagged = ReportFilter { tags: Vec::from_array(&env, [flood.clone()]), ..any.clone() };
+    assert_eq!(ids(client.search_reports(&tagged, &0u64, &10u32)), Vec::from_array(&env, [1u64, 3u64]));
+
+    let narrow = ReportFilter { min_amount: Some(1000), created_before: Some(300), ..tagged.clone() };
+    assert_eq!(ids(client.search_reports(&narrow, &0u64, &10u32)), Vec::from_array(&env, [3u64]));
+
+    let by_creator = ReportFilter { creator: Some(creator.clone()), category: Some(String::from_str(&env, "Medical")), ..any.clone() };
+    assert_eq!(ids(client.search_reports(&by_creator, &0u64, &10u32)), Vec::from_array(&env, [2u64]));
+
+    // Paging resumes after the cursor
+    let first = client.search_reports(&any, &0u64, &2u32);
+    assert_eq!(first.reports.len(), 2);
+    assert_eq!(first.next_cursor, Some(2));
+    let second = client.search_reports(&any, &2u64, &2u32);
+    assert_eq!(ids(second.clone()), Vec::from_array(&env, [3u64]));
+    assert_eq!(second.next_cursor, None);
+
+    // Retagging updates the index
+    client.set_tags(&report_ids.get(0).unwrap(), &creator, &Vec::from_array(&env, [children.clone()]));
+    assert_eq!(ids(client.search_reports(&tagged, &0u64, &10u32)), Vec::from_array(&env, [3u64]));
+}
+
+#[test]
+fn test_ranked_report_queries() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+
+    let creator = Address::generate(&env);
+    for (i, amount_needed) in [1000i128, 400i128, 2000i128].into_iter().enumerate() {
+        env.ledger().set_timestamp(100 * (i as u64 + 1));
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &amount_needed,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    }
+    // A pending report is not ranked
+    client.create_report(
+        &creator,
+        &String::from_str(&env, "Report"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &10i128,
+        &Vec::new(&env),
+    );
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &2200);
+    client.donate(&3u64, &donor, &1800i128, &0i128);
+    client.set_deadline(&1u64, &creator, &Some(5000u64));
+    client.set_deadline(&3u64, &creator, &Some(1000u64));
+    assert!(client.try_set_deadline(&2u64, &creator, &Some(50u64)).is_err());
+
+    let ids = |by: SortKey| {
+        let mut ids = Vec::new(&env);
+        for report in client.get_reports_sorted(&by, &0u64, &10u32).reports.iter() {
+            ids.push_back(report.id);
+        }
+        ids
+    };
+    assert_eq!(ids(SortKey::RemainingAmount), Vec::from_array(&env, [3u64, 2u64, 1u64]));
+    assert_eq!(ids(SortKey::PercentFunded), Vec::from_array(&env, [3u64, 1u64, 2u64]));
+    assert_eq!(ids(SortKey::Deadline), Vec::from_array(&env, [3u64, 1u64]));
+    assert_eq!(ids(SortKey::Recency), Vec::from_array(&env, [3u64, 2u64, 1u64]));
+
+    // Fully funded reports leave the rankings
+    client.donate(&2u64, &donor, &400i128, &0i128);
+    let page = client.get_reports_sorted(&SortKey::RemainingAmount, &0u64, &1u32);
+    assert_eq!(page.reports.get(0).unwrap().id, 3);
+    assert_eq!(page.next_cursor, Some(1));
+    let page = client.get_reports_sorted(&SortKey::RemainingAmount, &1u64, &1u32);
+    assert_eq!(page.reports.get(0).unwrap().id, 1);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_ranked_index_spans_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+
+    // Enough reports to split the index into several pages, inserted out of order
+    let creator = Address::generate(&env);
+    let count = SORT_PAGE_SIZE as u64 * 2 + 10;
+    for i in 0..count {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Report"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Food"),
+            &(((i * 37) % count + 1) as i128 * 10),
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    }
+
+    let amounts = |client: &NeedReportsContractClient| {
+        let mut amounts = Vec::new(&env);
+        let mut cursor = Some(0u64);
+        while let Some(position) = cursor {
+            let page = client.get_reports_sorted(&SortKey::RemainingAmount, &position, &50u32);
+            for report in page.reports.iter() {
+                amounts.push_back(report.amount_needed);
+            }
+            cursor = page.next_cursor;
+        }
+        amounts
+    };
+    let ranked = amounts(&client);
+    assert_eq!(ranked.len() as u64, count);
+    for i in 1..ranked.len() {
+        assert!(ranked.get(i - 1).unwrap() < ranked.get(i).unwrap());
+    }
+
+    // Rejecting reports drops them from whichever page holds them
+    for report_id in 1..=count / 2 {
+        client.update_status(&report_id, &admin, &String::from_str(&env, "rejected"), &String::from_str(&env, "Duplicate"));
+    }
+    let ranked = amounts(&client);
+    assert_eq!(ranked.len() as u64, count - count / 2);
+    for i in 1..ranked.len() {
+        assert!(ranked.get(i - 1).unwrap() < ranked.get(i).unwrap());
+    }
+}
+
+#[test]
+fn test_urgency_priority_and_weighting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let token = setup_token(&env, &client, &admin);
+    env.ledger().set_timestamp(100);
+
+    let creator = Address::generate(&env);
+    for _ in 0..3 {
+        let report_id = client.create_report(
+            &creator,
+            &String::from_str(&env, "Shelter"),
+            &String::from_str(&env, "Description"),
+            &String::from_str(&env, "Location"),
+            &geo(&env, "CO", "Antioquia", None),
+            &String::from_str(&env, "Shelter"),
+            &1000i128,
+            &Vec::new(&env),
+        );
+        client.update_status(&report_id, &admin, &String::from_str(&env, "verified"), &String::from_str(&env, "Verified"));
+    }
+    assert_eq!(client.get_report(&1u64).unwrap().urgency, String::from_str(&env, "medium"));
+
+    // Only verifiers may set urgency, and only to a known level
+    assert!(client.try_set_urgency(&1u64, &creator, &String::from_str(&env, "high"), &String::from_str(&env, "Please")).is_err());
+    assert!(client.try_set_urgency(&1u64, &admin, &String::from_str(&env, "extreme"), &String::from_str(&env, "Flood")).is_err());
+    assert!(client.set_urgency(&2u64, &admin, &String::from_str(&env, "critical"), &String::from_str(&env, "Flood")));
+    assert!(client.set_urgency(&3u64, &admin, &String::from_str(&env, "low"), &String::from_str(&env, "Stable")));
+
+    let log = client.get_change_log(&2u64);
+    let entry = log.get(log.len() - 1).unwrap();
+    assert_eq!(entry.field_changed, String::from_str(&env, "urgency"));
+    assert_eq!(entry.old_value, String::from_str(&env, "medium"));
+    assert_eq!(entry.new_value, String::from_str(&env, "critical"));
+
+    let page = client.get_reports_sorted(&SortKey::Priority, &0u64, &10u32);
+    let mut ids = Vec::new(&env);
+    for report in page.reports.iter() {
+        ids.push_back(report.id);
+    }
+    assert_eq!(ids, Vec::from_array(&env, [2u64, 1u64, 3u64]));
+
+    // Campaign split weighted 200 : 50 between the critical and low reports
+    let campaign_id = client.create_campaign(
+        &admin,
+        &String::from_str(&env, "Flood response"),
+        &100u64,
+        &10000u64,
+        &String::from_str(&env, "CO"),
+        &String::from_str(&env, "Antioquia"),
+        &String::from_str(&env, "Shelter"),
+        &SplitRule::UrgencyWeighted,
+    );
+    client.join_campaign(&campaign_id, &creator, &2u64);
+    client.join_campaign(&campaign_id, &creator, &3u64);
+
+    let donor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&donor, &600);
+    client.donate_to_campaign(&campaign_id, &donor, &500i128, &0i128);
+    assert_eq!(client.get_report(&2u64).unwrap().amount_raised, 400);
+    assert_eq!(client.get_report(&3u64).unwrap().amount_raised, 100);
+
+    // A 50% urgency-weighted pool doubles its match for critical reports
+    let sponsor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&sponsor, &1000);
+    client.create_matching_pool(&sponsor, &1000i128, &5000u32, &None, &None, &None, &1000i128, &true, &1000u64);
+    client.donate(&1u64, &donor, &100i128, &0i128);
+    assert_eq!(client.get_report(&1u64).unwrap().amount_raised, 150);
+}
+
+#[test]
+fn test_private_report_details() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, NeedReportsContract);
+    let client = NeedReportsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin.clone());
+    client.initialize(&admins);
+    register_categories(&env, &client, &admin);
+    let verifier = Address::generate(&env);
+    client.add_verifier(&admin, &verifier);
+
+    // The beneficiary's address is salted and hashed off chain
+    let plaintext = Bytes::from_slice(&env, b"salt:4f2a|Calle 10 #43-21, Medellin");
+    let commitment: BytesN<32> = env.crypto().sha256(&plaintext).into();
+    let creator = Address::generate(&env);
+    let report_id = client.create_private_report(
+        &creator,
+        &String::from_str(&env, "Medicine for a family"),
+        &String::from_str(&env, "Medellin area"),
+        &String::from_str(&env, "CO"),
+        &String::from_str(&env, "Antioquia"),
+        &String::from_str(&env, "Medical"),
+        &500i128,
+        &commitment,
+        &Vec::new(&env),
+    );
+
+    let report = client.get_report(&report_id).unwrap();
+    assert_eq!(report.description, String::from_str(&env, ""));
+    assert_eq!(report.geo.latitude_e6, None);
+    assert_eq!(client.get_reports_by_region(&String::from_str(&env, "CO"), &Some(String::from_str(&env, "Antioquia"))).len(), 1);
+    assert!(client.try_update_report(
+        &report_id,
+        &creator,
+        &None,
+        &Some(String::from_str(&env, "Calle 10 #43-21")),
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+        &String::from_str(&env, "Add address"),
+    ).is_err());
+    assert!(client.try_update_report(
+        &report_id,
+        &creator,
+        &None,
+        &None,
+        &Some(String::from_str(&env, "Calle 10 #43-21")),
+        &None,
+        &None,
+        &None,
+        &None,
+        &String::from_str(&env, "Add address"),
+    ).is_err());
+
+    // Envelopes can only be addressed to verifiers
+    let envelope = Bytes::from_slice(&env, &[7u8; 48]);
+    assert!(client.try_add_details_envelope(&report_id, &creator, &creator, &envelope).is_err());
+    assert!(client.add_details_envelope(&report_id, &creator, &verifier, &envelope));
+    assert_eq!(client.get_private_details(&report_id).unwrap().envelopes.get(verifier.clone()), Some(envelope));
+
+    // Verification waits for an attestation that matches the commitment
+    let verified = String::from_str(&env, "verified");
+    assert!(client.try_update_status(&report_id, &verifier, &verified, &String::from_str(&env, "Checked")).is_err());
+    assert!(client.try_attest_details(&report_id, &verifier, &BytesN::from_array(&env, &[0u8; 32])).is_err());
+    assert!(client.attest_details(&report_id, &verifier, &commitment));
+    assert!(client.try_attest_details(&report_id, &verifier, &commitment).is_err());
+    assert!(client.update_status(&report_id, &verifier, &verified, &String::from_str(&env, "Checked")));
+    assert_eq!(client.get_private_details(&report_id).unwrap().attestations.get(0).unwrap().verifier, verifier);
+    assert!(client.bump_report(&report_id));
+
+    // Plain reports are unaffected
+    let plain_id = client.create_report(
+        &creator,
+        &String::from_str(&env, "Report"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Location"),
+        &geo(&env, "CO", "Antioquia", None),
+        &String::from_str(&env, "Food"),
+        &100i128,
+        &Vec::new(&env),
+    );
+    assert_eq!(client.get_private_details(&plain_id), None);
+    assert!(client.update_status(&plain_id, &verifier, &verified, &String::from_str(&env, "Checked")));
+}
@@ -0,0 +1,106 @@
+use soroban_sdk::{contracttype, symbol_short, Env, IntoVal, Symbol, Val};
+
+/// Kinds of stored data that carry their own TTL policy
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataClass {
+    Instance, // Config, role lists and counters
+    Report,
+    ChangeLog,
+    Funds, // Donations, escrow balances and round contributions
+    Record, // Organizations, pools, rounds, campaigns, disputes and other per-entity records
+    Index, // Lookup lists such as the geographic indexes
+}
+
+/// Extend an entry to `extend_to` ledgers once its TTL drops below `threshold`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+/// Estimated expiry of a report entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportExpiry {
+    pub report_id: u64,
+    pub live_until_ledger: u32,
+}
+
+const TTL_POLICIES: Symbol = symbol_short!("TTL_POL");
+const REPORT_LIVE_UNTIL: Symbol = symbol_short!("LIVE_UNTL");
+
+/// Defaults applied to every class until an admin configures it
+pub const DEFAULT_EXTEND_TO: u32 = 5184000;
+pub const DEFAULT_THRESHOLD: u32 = DEFAULT_EXTEND_TO / 2;
+
+/// Get the policy for a data class
+pub fn get_policy(env: &Env, class: DataClass) -> TtlPolicy {
+    env.storage().instance()
+        .get::<(Symbol, DataClass), TtlPolicy>(&(TTL_POLICIES, class))
+        .unwrap_or(TtlPolicy {
+            threshold: DEFAULT_THRESHOLD,
+            extend_to: DEFAULT_EXTEND_TO,
+        })
+}
+
+/// Store the policy for a data class
+pub fn set_policy(env: &Env, class: DataClass, policy: &TtlPolicy) {
+    if policy.threshold == 0 || policy.threshold > policy.extend_to {
+        panic!("TTL threshold must be positive and not above the extension");
+    }
+    if policy.extend_to > env.storage().max_ttl() {
+        panic!("TTL extension exceeds the network maximum");
+    }
+    env.storage().instance().set(&(TTL_POLICIES, class), policy);
+    extend_instance(env);
+}
+
+/// Extend the contract instance per the Instance policy
+pub fn extend_instance(env: &Env) {
+    let policy = get_policy(env, DataClass::Instance);
+    env.storage().instance().extend_ttl(policy.threshold, policy.extend_to);
+}
+
+/// Extend a persistent entry per its class policy
+pub fn extend<K: IntoVal<Env, Val>>(env: &Env, key: &K, class: DataClass) {
+    let policy = get_policy(env, class);
+    env.storage().persistent().extend_ttl(key, policy.threshold, policy.extend_to);
+}
+
+/// Extend a persistent entry if it still exists, returning whether it did
+pub fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K, class: DataClass) -> bool {
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    extend(env, key, class);
+    true
+}
+
+/// Extend a report entry and record its estimated expiry
+///
+/// Contracts cannot read entry TTLs, so the host's extension rule is mirrored here to
+/// keep `live_until` in step. The record is only rewritten when an extension happens.
+pub fn extend_report(env: &Env, report_id: u64) {
+    let policy = get_policy(env, DataClass::Report);
+    env.storage().persistent().extend_ttl(&report_id, policy.threshold, policy.extend_to);
+
+    let now = env.ledger().sequence();
+    let key = (REPORT_LIVE_UNTIL, report_id);
+    let live_until = report_live_until(env, report_id).unwrap_or(0);
+    if live_until.saturating_sub(now) < policy.threshold {
+        env.storage().persistent().set(&key, &(now + policy.extend_to));
+        env.storage().persistent().extend_ttl(&key, policy.threshold, policy.extend_to);
+    }
+}
+
+/// Drop the expiry record of a report that no longer exists
+pub fn forget_report(env: &Env, report_id: u64) {
+    env.storage().persistent().remove(&(REPORT_LIVE_UNTIL, report_id));
+}
+
+/// Estimated last ledger a report entry stays live, if it was extended through this module
+pub fn report_live_until(env: &Env, report_id: u64) -> Option<u32> {
+    env.storage().persistent().get::<(Symbol, u64), u32>(&(REPORT_LIVE_UNTIL, report_id))
+}
@@ -0,0 +1,13 @@
+// Need report amounts are stored on-chain in the donation token's smallest unit.
+// Each report records the token's decimals; 7 is the Stellar asset default.
+export const DEFAULT_TOKEN_DECIMALS = 7
+
+// Convert a display amount (e.g. 12.5) to token units for the contract
+export function toTokenUnits(amount: number, decimals: number = DEFAULT_TOKEN_DECIMALS): number {
+  return Math.round(amount * 10 ** decimals)
+}
+
+// Convert token units from the contract back to a display amount
+export function fromTokenUnits(units: number, decimals: number = DEFAULT_TOKEN_DECIMALS): number {
+  return units / 10 ** decimals
+}
@@ -7,7 +7,8 @@ import {
   Address,
   nativeToScVal,
   scValToNative,
-  Operation
+  Operation,
+  xdr
 } from '@stellar/stellar-sdk'
 
 const NEED_REPORTS_CONTRACT_ID = process.env.NEED_REPORTS_CONTRACT_ID || 'CCONK5WC3MDUIOJJ4G3KFO4BXYYMP3GWSLMFANDULFETRFCOMJ3ZWLY7'
@@ -30,6 +31,31 @@ try {
   console.error('❌ Create route: Failed to initialize Soroban server:', error)
 }
 
+// Structured location accepted by the contract's GeoLocation type
+interface GeoInput {
+  countryCode: string // ISO 3166-1 alpha-2, e.g. "CO"
+  region: string // State / department, e.g. "Antioquia"
+  latitudeE6?: number | null // Degrees * 1_000_000
+  longitudeE6?: number | null // Degrees * 1_000_000
+  geohash?: string | null
+}
+
+// Helper function to encode a GeoLocation struct (an ScMap with fields in key order; None is void)
+function geoToScVal(geo: GeoInput) {
+  const optional = (value: any, type: string) =>
+    value === undefined || value === null ? xdr.ScVal.scvVoid() : nativeToScVal(value, { type })
+  const field = (name: string, val: xdr.ScVal) =>
+    new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(name), val })
+  
+  return xdr.ScVal.scvMap([
+    field('country_code', nativeToScVal(geo.countryCode.toUpperCase(), { type: 'string' })),
+    field('geohash', optional(geo.geohash, 'string')),
+    field('latitude_e6', optional(geo.latitudeE6, 'i64')),
+    field('longitude_e6', optional(geo.longitudeE6, 'i64')),
+    field('region', nativeToScVal(geo.region, { type: 'string' }))
+  ])
+}
+
 // Helper function to call contract methods with fee sponsorship
 async function callContract(contractId: string, method: string, args: any[] = [], sourceSecret: string, userPrivateKey?: string) {
   try {
@@ -188,16 +214,22 @@ export default async function handler(req: NextApiRequest, res: NextApiResponse)
       title,
       description,
       location,
+      geo,
       category,
       amountNeeded,
       imageUrls = []
     } = req.body
 
-    if (!userPrivateKey || !title || !description || !location || !category || !amountNeeded) {
+    if (!userPrivateKey || !title || !description || !location || !geo?.countryCode || !geo?.region || !category || !amountNeeded) {
       console.log('❌ Missing required fields')
       return res.status(400).json({ error: 'Missing required fields' })
     }
 
+    if (!/^[A-Za-z]{2}$/.test(geo.countryCode)) {
+      console.log('❌ Invalid country code:', geo.countryCode)
+      return res.status(400).json({ error: 'Country code must be ISO 3166-1 alpha-2 (e.g. "CO")' })
+    }
+
     console.log('✅ All required fields present')
 
     if (!STELLAR_FUNDING_SECRET) {
@@ -233,8 +265,9 @@ export default async function handler(req: NextApiRequest, res: NextApiResponse)
       nativeToScVal(title, { type: 'string' }), // title
       nativeToScVal(description, { type: 'string' }), // description
       nativeToScVal(location, { type: 'string' }), // location
+      geoToScVal(geo), // geo
       nativeToScVal(category, { type: 'string' }), // category
-      nativeToScVal(amountNeeded, { type: 'u64' }), // amount_needed
+      nativeToScVal(amountNeeded, { type: 'i128' }), // amount_needed
       nativeToScVal(imageUrlsVector, { type: 'vector' }) // image_urls
     ]
     
@@ -6,7 +6,8 @@ import {
   TransactionBuilder,
   Address,
   nativeToScVal,
-  scValToNative
+  scValToNative,
+  xdr
 } from '@stellar/stellar-sdk'
 
 const NEED_REPORTS_CONTRACT_ID = process.env.NEED_REPORTS_CONTRACT_ID || 'CBJVRBD5TCCM3BF22NDZPBSMU7VON5LQZBQOW3HMTN3PFDWD2TLW34XW'
@@ -29,6 +30,31 @@ try {
   console.error('❌ Update route: Failed to initialize Soroban server:', error)
 }
 
+// Structured location accepted by the contract's GeoLocation type
+interface GeoInput {
+  countryCode: string // ISO 3166-1 alpha-2, e.g. "CO"
+  region: string // State / department, e.g. "Antioquia"
+  latitudeE6?: number | null // Degrees * 1_000_000
+  longitudeE6?: number | null // Degrees * 1_000_000
+  geohash?: string | null
+}
+
+// Helper function to encode a GeoLocation struct (an ScMap with fields in key order; None is void)
+function geoToScVal(geo: GeoInput) {
+  const optional = (value: any, type: string) =>
+    value === undefined || value === null ? xdr.ScVal.scvVoid() : nativeToScVal(value, { type })
+  const field = (name: string, val: xdr.ScVal) =>
+    new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(name), val })
+  
+  return xdr.ScVal.scvMap([
+    field('country_code', nativeToScVal(geo.countryCode.toUpperCase(), { type: 'string' })),
+    field('geohash', optional(geo.geohash, 'string')),
+    field('latitude_e6', optional(geo.latitudeE6, 'i64')),
+    field('longitude_e6', optional(geo.longitudeE6, 'i64')),
+    field('region', nativeToScVal(geo.region, { type: 'string' }))
+  ])
+}
+
 // Helper function to call contract methods
 async function callContract(contractId: string, method: string, args: any[] = [], sourceSecret: string) {
   try {
@@ -143,9 +169,10 @@ export default async function handler(req: NextApiRequest, res: NextApiResponse)
       title,
       description,
       location,
+      geo,
       category,
       amountNeeded,
-      imageUrls = [],
+      imageUrls,
       reason
     } = req.body
 
@@ -176,39 +203,28 @@ export default async function handler(req: NextApiRequest, res: NextApiResponse)
 
     // Convert image URLs array to Soroban vector format
     console.log('🖼️ Processing image URLs...')
-    const imageUrlsVector = imageUrls.map((url: string) => nativeToScVal(url, { type: 'string' }))
-    console.log('✅ Image URLs processed:', imageUrls.length, 'images')
+    const imageUrlsVector = (imageUrls || []).map((url: string) => nativeToScVal(url, { type: 'string' }))
+    console.log('✅ Image URLs processed:', imageUrlsVector.length, 'images')
 
     // Call update_report function using Stellar SDK
     console.log('🔄 Updating need report using Stellar SDK...')
     console.log('⏳ This may take a few seconds...')
     
-    // Prepare contract parameters - only include defined fields
+    // Prepare contract parameters in ABI order - fields that weren't provided are passed as None (void)
+    const optional = (value: any, toScVal: (value: any) => xdr.ScVal) =>
+      value === undefined ? xdr.ScVal.scvVoid() : toScVal(value)
     const contractArgs = [
-      nativeToScVal(parseInt(reportId), { type: 'u32' }), // report_id
+      nativeToScVal(BigInt(reportId), { type: 'u64' }), // report_id
       nativeToScVal(userAddress, { type: 'address' }), // updater
+      optional(title, (value) => nativeToScVal(value, { type: 'string' })), // title
+      optional(description, (value) => nativeToScVal(value, { type: 'string' })), // description
+      optional(location, (value) => nativeToScVal(value, { type: 'string' })), // location
+      optional(geo, geoToScVal), // geo
+      optional(category, (value) => nativeToScVal(value, { type: 'string' })), // category
+      optional(amountNeeded, (value) => nativeToScVal(value, { type: 'i128' })), // amount_needed
+      optional(imageUrls, () => nativeToScVal(imageUrlsVector, { type: 'vector' })), // image_urls
       nativeToScVal(reason, { type: 'string' }) // reason
     ]
-
-    // Add optional fields only if they are provided
-    if (title !== undefined) {
-      contractArgs.push(nativeToScVal(title, { type: 'string' }))
-    }
-    if (description !== undefined) {
-      contractArgs.push(nativeToScVal(description, { type: 'string' }))
-    }
-    if (location !== undefined) {
-      contractArgs.push(nativeToScVal(location, { type: 'string' }))
-    }
-    if (category !== undefined) {
-      contractArgs.push(nativeToScVal(category, { type: 'string' }))
-    }
-    if (amountNeeded !== undefined) {
-      contractArgs.push(nativeToScVal(amountNeeded, { type: 'u64' }))
-    }
-    if (imageUrls.length > 0) {
-      contractArgs.push(nativeToScVal(imageUrlsVector, { type: 'vector' }))
-    }
     
     // Try funding account first (app-sponsored), then fallback to user
     let result
@@ -9,6 +9,7 @@ import {
   nativeToScVal,
   scValToNative
 } from '@stellar/stellar-sdk'
+import { DEFAULT_TOKEN_DECIMALS } from '@/lib/amounts'
 
 const NEED_REPORTS_CONTRACT_ID = process.env.NEED_REPORTS_CONTRACT_ID || 'CCONK5WC3MDUIOJJ4G3KFO4BXYYMP3GWSLMFANDULFETRFCOMJ3ZWLY7'
 
@@ -206,6 +207,7 @@ export default async function handler(req: NextApiRequest, res: NextApiResponse)
           category: report.category || '',
           amountNeeded: typeof report.amount_needed === 'bigint' ? Number(report.amount_needed) : (parseInt(report.amount_needed) || 0),
           amountRaised: typeof report.amount_raised === 'bigint' ? Number(report.amount_raised) : (parseInt(report.amount_raised) || 0),
+          decimals: report.decimals !== undefined ? Number(report.decimals) : DEFAULT_TOKEN_DECIMALS,
           status: mapContractStatus(report.status || 'pending'),
           imageUrl: report.image_urls && report.image_urls.length > 0 ? report.image_urls[0] : '/placeholder.svg',
           imageUrls: report.image_urls || [],